@@ -1,5 +1,22 @@
+pub mod abapsource;
+pub mod abapunit;
+pub mod activation;
+pub mod atc;
+pub mod cds;
 pub mod checkruns;
+pub mod classes;
 pub mod core;
+pub mod cts;
+pub mod datapreview;
+pub mod deletion;
+pub mod functions;
+mod headers;
+pub mod includes;
+pub mod interfaces;
+pub mod navigation;
 pub mod object;
+pub mod packages;
 pub mod programs;
+pub mod quickfixes;
+pub mod refactoring;
 pub mod repository;
@@ -0,0 +1,485 @@
+/// Operations on ABAP source code that are independent of the object type, e.g. the
+/// pretty printer, code completion or the type hierarchy.
+///
+/// Code completion and the other editor features work on the unsaved source code of the editor, which is sent along
+/// with the cursor position as [`SourceLocation`], e.g. `…/source/main#start=12,8`
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    Client, QueryParameters, RequestDispatch,
+    api::object::{SourceCodeObject, UpdateSourceCode},
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    error::OperationError,
+    models::{
+        abapsource::{PrettyPrinterSettings, SourceLocation},
+        asx::AsxData,
+        codecompletion::{ElementInfo, Proposal, Proposals},
+        hierarchy, occurrencemarkers,
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateful, Stateless},
+    response::{Plain, Success},
+    session::UserSessionId,
+};
+
+const SETTINGS_CONTENT_TYPE: &str = "application/vnd.sap.adt.ppsettings.v2+xml";
+
+/// Formats source code according to the pretty printer settings of the logged on user.
+///
+/// The response body is the formatted source code.
+///
+/// Operation `/sap/bc/adt/abapsource/prettyprinter`
+#[derive(Builder, Debug)]
+pub struct PrettyPrint<'a> {
+    /// The source code to format.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for PrettyPrint<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<Plain<'static>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Fetches the pretty printer settings of the logged on user.
+///
+/// Operation `/sap/bc/adt/abapsource/prettyprinter/settings`
+#[derive(Builder, Debug, Default)]
+pub struct PrettyPrinterSettingsRead {}
+
+impl Operation for PrettyPrinterSettingsRead {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<PrettyPrinterSettings>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter/settings".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(SETTINGS_CONTENT_TYPE),
+        );
+        Some(headers)
+    }
+}
+
+/// Replaces the pretty printer settings of the logged on user.
+///
+/// Operation `/sap/bc/adt/abapsource/prettyprinter/settings`
+#[derive(Builder, Debug)]
+pub struct PrettyPrinterSettingsUpdate<'a> {
+    /// The new settings, usually the ones read through [`PrettyPrinterSettingsRead`]
+    settings: &'a PrettyPrinterSettings,
+}
+
+impl Operation for PrettyPrinterSettingsUpdate<'_> {
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Kind = Stateless;
+    type Response = Success<()>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter/settings".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(SETTINGS_CONTENT_TYPE),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.settings.into_xml_root())
+    }
+}
+
+/// Lists the completion proposals for the cursor position.
+///
+/// Operation `/sap/bc/adt/abapsource/codecompletion/proposal?uri={uri}#start={line},{column}`
+#[derive(Builder, Debug)]
+pub struct CompletionProposals<'a> {
+    /// The source and the cursor position in it, e.g. `…/zwegwerf1/source/main#start=8,4`
+    location: SourceLocation,
+
+    /// The current, possibly unsaved source code of the editor.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for CompletionProposals<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<AsxData<Proposals>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/proposal".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params.push("signalCompleteness", true);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        source_headers("application/vnd.sap.as+xml")
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Fetches the full text to insert for a proposal, e.g. a method call with its parameters.
+///
+/// Only available for proposals with [`crate::models::codecompletion::Proposal::has_insertion`]
+///
+/// Operation `/sap/bc/adt/abapsource/codecompletion/insertion?uri={uri}&patternKey={identifier}`
+#[derive(Builder, Debug)]
+pub struct CompletionInsertion<'a> {
+    /// The source and the cursor position in it, e.g. `…/zwegwerf1/source/main#start=8,4`
+    location: SourceLocation,
+
+    /// The current, possibly unsaved source code of the editor.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+
+    /// The identifier of the proposal to insert.
+    #[builder(setter(into))]
+    identifier: Cow<'a, str>,
+}
+
+impl Operation for CompletionInsertion<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<Plain<'static>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/insertion".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params.push("patternKey", &self.identifier);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        source_headers("text/plain")
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Fetches the information about the element at the cursor position, e.g. its documentation.
+///
+/// Operation `/sap/bc/adt/abapsource/codecompletion/elementinfo?uri={uri}`
+#[derive(Builder, Debug)]
+pub struct CompletionElementInfo<'a> {
+    /// The source and the position of the element in it, e.g. `…/zwegwerf1/source/main#start=8,4`
+    location: SourceLocation,
+
+    /// The current, possibly unsaved source code of the editor.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for CompletionElementInfo<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ElementInfo>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/elementinfo".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        source_headers("application/xml")
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// The direction of a type hierarchy, starting at the class or interface at the position.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HierarchyDirection {
+    /// The super classes and implemented interfaces.
+    SuperTypes,
+    /// The sub classes and, for interfaces, the implementing classes.
+    #[default]
+    SubTypes,
+}
+
+impl HierarchyDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SuperTypes => "superTypes",
+            Self::SubTypes => "subTypes",
+        }
+    }
+}
+
+/// Lists the super or sub types of the class or interface at the position.
+///
+/// Operation `/sap/bc/adt/abapsource/typehierarchy?uri={uri}&type={direction}`
+#[derive(Builder, Debug)]
+pub struct TypeHierarchy<'a> {
+    /// The source and the position of the type in it, e.g. `…/zcl_demo/source/main#start=1,6`
+    location: SourceLocation,
+
+    /// Whether super or sub types are listed, sub types by default.
+    #[builder(default)]
+    direction: HierarchyDirection,
+
+    /// The current, possibly unsaved source code of the editor.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for TypeHierarchy<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<hierarchy::TypeHierarchy>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/typehierarchy".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params.push("type", self.direction.as_str());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        source_headers("application/xml")
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Lists all occurrences of the identifier at the position within the source.
+///
+/// Operation `/sap/bc/adt/abapsource/occurencemarkers?uri={uri}`
+#[derive(Builder, Debug)]
+pub struct OccurrenceMarkers<'a> {
+    /// The source and the position of the identifier in it, e.g. `…/zwegwerf1/source/main#start=8,4`
+    location: SourceLocation,
+
+    /// The current, possibly unsaved source code of the editor.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for OccurrenceMarkers<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<occurrencemarkers::OccurrenceMarkers>;
+
+    // The misspelling is part of the endpoint.
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/occurencemarkers".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        source_headers("application/xml")
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+fn source_headers(accept: &'static str) -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    headers.insert(header::ACCEPT, HeaderValue::from_static(accept));
+    Some(headers)
+}
+
+/// Reads the current source code of an object within the session that holds its lock.
+struct LockedSource<'a> {
+    object: &'a SourceCodeObject<'a>,
+}
+
+impl Operation for LockedSource<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateful;
+    type Response = Success<Plain<'static>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.object.source_code_uri().into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+}
+
+/// Determines the text that replaces the [`Proposal::prefix_length`] characters before the
+/// cursor when the proposal is chosen.
+///
+/// The full pattern is fetched through [`CompletionInsertion`] for proposals with
+/// [`Proposal::has_insertion`], e.g. a method call with its parameters. Any other proposal
+/// is inserted as its identifier.
+pub async fn insertion_text<T>(
+    client: &Client<T>,
+    location: &SourceLocation,
+    source: &str,
+    proposal: &Proposal,
+) -> Result<String, OperationError>
+where
+    T: RequestDispatch,
+{
+    if !proposal.has_insertion {
+        return Ok(proposal.identifier.clone());
+    }
+
+    let insertion = CompletionInsertion {
+        location: location.clone(),
+        source: source.into(),
+        identifier: proposal.identifier.as_str().into(),
+    }
+    .dispatch(client)
+    .await?;
+    Ok(insertion.body().to_string())
+}
+
+/// Formats the source code of a locked object through [`PrettyPrint`] and writes it back.
+///
+/// The object has to be locked in the given session, the transport is passed on to
+/// [`crate::api::object::UpdateSourceCode`]. Returns whether the source code was changed,
+/// already formatted source code is not written back.
+pub async fn pretty_print_object<T>(
+    client: &Client<T>,
+    ctx: UserSessionId,
+    object: &SourceCodeObject<'_>,
+    lock_handle: &str,
+    transport: Option<&str>,
+) -> Result<bool, OperationError>
+where
+    T: RequestDispatch,
+{
+    let source = LockedSource { object }.dispatch(client, ctx).await?;
+    let formatted = PrettyPrint {
+        source: source.body().as_ref().into(),
+    }
+    .dispatch(client)
+    .await?;
+
+    if formatted.body().as_ref() == source.body().as_ref() {
+        return Ok(false);
+    }
+
+    let op = UpdateSourceCode {
+        object: object.clone(),
+        lock_handle: lock_handle.into(),
+        content: formatted.body().as_ref().into(),
+        transport: transport.map(Into::into),
+    };
+    op.dispatch(client, ctx).await?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::abapsource::Position, testing::FakeSystem};
+    use http::Method;
+
+    fn proposal(identifier: &str, has_insertion: bool) -> Proposal {
+        let insert_event = u8::from(has_insertion);
+        let plain = format!(
+            r#"<SCC_COMPLETION>
+                <KIND>1</KIND>
+                <IDENTIFIER>{identifier}</IDENTIFIER>
+                <INSERT_EVENT>{insert_event}</INSERT_EVENT>
+            </SCC_COMPLETION>"#
+        );
+        serde_xml_rs::from_str(&plain).unwrap()
+    }
+
+    #[tokio::test]
+    async fn insertion_is_fetched_for_patterns_only() {
+        let system = FakeSystem::responding("lo_demo->run( iv_count = ).");
+        let client = system.client();
+        let location = SourceLocation::new(
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+            Position::new(8, 4),
+            None,
+        );
+
+        let variable = proposal("lv_counter", false);
+        let text = insertion_text(&client, &location, "", &variable).await;
+        assert_eq!(text.unwrap(), "lv_counter");
+        assert!(!system.requested(Method::POST, "/codecompletion/insertion"));
+
+        let method = proposal("RUN", true);
+        let text = insertion_text(&client, &location, "", &method).await;
+        assert_eq!(text.unwrap(), "lo_demo->run( iv_count = ).");
+        assert!(system.requested(Method::POST, "/codecompletion/insertion"));
+    }
+}
@@ -0,0 +1,124 @@
+/// Operations to run ABAP Unit tests and to fetch the coverage measured during a test run.
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::{
+        abapunit::{self, CoverageQuery, CoverageResult, RunConfiguration, RunResult},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+/// Runs the unit tests of the objects and packages in the configuration and waits for the result.
+///
+/// Operation `/sap/bc/adt/abapunit/testruns`
+#[derive(Builder, Debug)]
+pub struct RunTests<'a> {
+    /// What to test, see [`abapunit::RunConfigurationBuilder`]
+    config: &'a RunConfiguration,
+}
+
+impl Operation for RunTests<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<RunResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapunit/testruns".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        QueryParameters::default()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/vnd.sap.adt.abapunit.testruns.config.v4+xml"),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.abapunit.testruns.result.v2+xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.config.into_xml_root())
+    }
+}
+
+/// Fetches the capabilities of the ABAP Unit runner, e.g. whether coverage can be measured.
+///
+/// Operation `/sap/bc/adt/abapunit/metadata`
+#[derive(Builder, Debug, Default)]
+pub struct Metadata {}
+
+impl Operation for Metadata {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<abapunit::Metadata>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapunit/metadata".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.abapunit.metadata+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Fetches the coverage tree of the given objects from a measurement of a test run.
+///
+/// Operation `/sap/bc/adt/runtime/traces/coverage/measurements/{id}`
+#[derive(Builder, Debug)]
+pub struct CoverageMeasurement<'a> {
+    /// The URI of the measurement, see [`abapunit::RunResult::coverage`]
+    #[builder(setter(into))]
+    measurement_uri: Cow<'a, str>,
+
+    /// The URIs of the objects to fetch the coverage for, e.g. the tested ones.
+    #[builder(setter(each(name = "object", into)))]
+    objects: Vec<String>,
+}
+
+impl Operation for CoverageMeasurement<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<CoverageResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.measurement_uri.to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        QueryParameters::default()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(CoverageQuery::new(&self.objects).into_xml_root())
+    }
+}
@@ -0,0 +1,282 @@
+/// Operations to activate objects, either synchronously or as background run,
+/// and to list the inactive objects of the system.
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    Client, QueryParameters, RequestDispatch,
+    dispatch::StatelessDispatch,
+    error::OperationError,
+    models::{
+        activation::{
+            self, ActivationObject, ActivationObjects, ActivationObjectsBuilderError,
+            ActivationResult, ActivationRun,
+        },
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+/// Activates the given objects and waits for the activation to finish.
+///
+/// With the pre-audit requested, the server first checks whether other inactive objects have to
+/// be activated along with the given ones and reports them instead of activating,
+/// see [`ActivationResult::pending`].
+///
+/// Operation `/sap/bc/adt/activation?method=activate`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct Activate<'a> {
+    objects: &'a ActivationObjects,
+
+    /// Whether the server should check for dependent inactive objects first.
+    ///
+    /// When unspecified in the query, the server does not perform the pre-audit.
+    #[builder(default)]
+    preaudit: Option<bool>,
+}
+
+impl Operation for Activate<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ActivationResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "activation".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("method", "activate");
+        params.push_opt("preauditRequested", self.preaudit);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.objects.into_xml_root())
+    }
+}
+
+/// Lists the inactive objects of all users along with the transports they are recorded in.
+///
+/// Operation `/sap/bc/adt/activation/inactiveobjects`
+#[derive(Builder, Debug, Default)]
+pub struct InactiveObjects {}
+
+impl Operation for InactiveObjects {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<activation::InactiveObjects>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "activation/inactiveobjects".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.inactivectsobjects.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Starts the activation of the given objects as background run.
+///
+/// The server acknowledges with `201 Created`, the `Location` header points to the run,
+/// see [`run_id`] to extract its id for [`ActivationRunStatus`] and [`ActivationRunResult`].
+///
+/// Operation `/sap/bc/adt/activation/runs?method=activate`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct StartActivationRun<'a> {
+    objects: &'a ActivationObjects,
+
+    /// Whether the server should check for dependent inactive objects first.
+    #[builder(default)]
+    preaudit: Option<bool>,
+}
+
+impl Operation for StartActivationRun<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<()>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "activation/runs".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("method", "activate");
+        params.push_opt("preauditRequested", self.preaudit);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.objects.into_xml_root())
+    }
+}
+
+/// The id of a run started through [`StartActivationRun`], taken from the `Location` header.
+pub fn run_id<T>(response: &http::Response<T>) -> Option<&str> {
+    response
+        .headers()
+        .get(header::LOCATION)?
+        .to_str()
+        .ok()?
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+}
+
+/// Polls the state of an activation run.
+///
+/// With long polling, the server only answers once the run finished or a timeout is reached.
+///
+/// Operation `/sap/bc/adt/activation/runs/{id}`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct ActivationRunStatus<'a> {
+    /// The id of the run, see [`run_id`]
+    #[builder(setter(into))]
+    id: Cow<'a, str>,
+
+    /// Whether the server should hold the request until the run finished.
+    #[builder(default)]
+    long_polling: Option<bool>,
+}
+
+impl Operation for ActivationRunStatus<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<ActivationRun>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("activation/runs/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("withLongPolling", self.long_polling);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+}
+
+/// Fetches the result of a finished activation run.
+///
+/// Operation `/sap/bc/adt/activation/results/{id}`
+#[derive(Builder, Debug)]
+pub struct ActivationRunResult<'a> {
+    /// The id of the run, see [`run_id`]
+    #[builder(setter(into))]
+    id: Cow<'a, str>,
+}
+
+impl Operation for ActivationRunResult<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<ActivationResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("activation/results/{}", self.id).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+}
+
+/// Activates all inactive objects last changed by the given user.
+///
+/// Lists the inactive objects through [`InactiveObjects`] and activates the ones of the user
+/// through [`Activate`]. Returns `None` if the user has no inactive objects.
+pub async fn activate_inactive<T>(
+    client: &Client<T>,
+    user: &str,
+) -> Result<Option<Success<ActivationResult>>, OperationError>
+where
+    T: RequestDispatch,
+{
+    let inactive = InactiveObjects::default().dispatch(client).await?;
+
+    let objects: Vec<_> = inactive
+        .body()
+        .objects_of(user)
+        .map(|object| ActivationObject::from(&object.reference))
+        .collect();
+    if objects.is_empty() {
+        return Ok(None);
+    }
+
+    let objects = activation::ActivationObjectsBuilder::default()
+        .objects(objects)
+        .build()
+        .map_err(|err| match err {
+            ActivationObjectsBuilderError::UninitializedField(field) => {
+                OperationError::UninitializedField(field)
+            }
+            ActivationObjectsBuilderError::ValidationError(message) => {
+                OperationError::InvalidField(message)
+            }
+        })?;
+
+    let op = Activate {
+        objects: &objects,
+        preaudit: None,
+    };
+    Ok(Some(op.dispatch(client).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_id_is_taken_from_location() {
+        let response = http::Response::builder()
+            .header(
+                header::LOCATION,
+                "/sap/bc/adt/activation/runs/0242AC1100021EDF",
+            )
+            .body(())
+            .unwrap();
+
+        assert_eq!(run_id(&response), Some("0242AC1100021EDF"));
+    }
+}
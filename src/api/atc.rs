@@ -0,0 +1,253 @@
+/// Operations of the ABAP Test Cockpit (ATC): customizing, check runs, worklists and exemptions.
+///
+/// A run is started for a worklist, which is created for a check variant first. The findings
+/// of the run are then read from the worklist:
+/// [`CreateWorklist`] → [`RunChecks`] → [`Worklist`]
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::{
+        atc::{self, Customizing, ExemptionRequest, ExemptionStatus, RunRequest, WorklistRun},
+        nameditem::NamedItemList,
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::{Plain, Success},
+};
+
+/// Fetches the ATC customizing, e.g. the system check variant and the exemption reasons.
+///
+/// Operation `/sap/bc/adt/atc/customizing`
+#[derive(Builder, Debug, Default)]
+pub struct AtcCustomizing {}
+
+impl Operation for AtcCustomizing {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<Customizing>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/customizing".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/xml, application/vnd.sap.atc.customizing-v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Searches the check variants by name.
+///
+/// Operation `/sap/bc/adt/atc/checkvariants`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct CheckVariants<'a> {
+    /// The pattern the names must match, e.g. `Z*`
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+
+    /// The maximum number of variants to return.
+    #[builder(default)]
+    max_items: Option<u64>,
+}
+
+impl Operation for CheckVariants<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<NamedItemList>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/checkvariants".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("name", self.name.as_ref());
+        params.push_opt("maxItemCount", self.max_items);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.nameditems.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a worklist for a check variant, the body of the response is the id of the worklist.
+///
+/// Operation `/sap/bc/adt/atc/worklists?checkVariant={variant}`
+#[derive(Builder, Debug)]
+pub struct CreateWorklist<'a> {
+    /// The check variant, e.g. the one of [`Customizing::system_check_variant`]
+    #[builder(setter(into))]
+    check_variant: Cow<'a, str>,
+}
+
+impl Operation for CreateWorklist<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<Plain<'static>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/worklists".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("checkVariant", &self.check_variant);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+}
+
+/// Runs the checks of a worklist on a set of objects and waits for the run to finish.
+///
+/// Operation `/sap/bc/adt/atc/runs?worklistId={id}`
+#[derive(Builder, Debug)]
+pub struct RunChecks<'a> {
+    /// The id of the worklist, see [`CreateWorklist`]
+    #[builder(setter(into))]
+    worklist_id: Cow<'a, str>,
+
+    /// The objects to check, see [`atc::RunRequestBuilder`]
+    request: &'a RunRequest,
+}
+
+impl Operation for RunChecks<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<WorklistRun>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/runs".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("worklistId", &self.worklist_id);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+/// Fetches a worklist with the findings of its runs.
+///
+/// Operation `/sap/bc/adt/atc/worklists/{id}`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct Worklist<'a> {
+    /// The id of the worklist, see [`CreateWorklist`]
+    #[builder(setter(into))]
+    id: Cow<'a, str>,
+
+    /// Restricts the findings to the run at the given point in time, see [`WorklistRun::timestamp`]
+    #[builder(default)]
+    timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Whether findings with an exemption should be included.
+    ///
+    /// When unspecified in the query, the server leaves them out.
+    #[builder(default)]
+    include_exempted: Option<bool>,
+}
+
+impl Operation for Worklist<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<atc::Worklist>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("atc/worklists/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt(
+            "timestamp",
+            self.timestamp.map(|timestamp| timestamp.timestamp() as u64),
+        );
+        params.push_opt("includeExemptedFindings", self.include_exempted);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/atc.worklist.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Requests an exemption for a finding, which has to be approved by the approver.
+///
+/// Operation `/sap/bc/adt/atc/checkexemptions`
+#[derive(Builder, Debug)]
+pub struct RequestExemption<'a> {
+    /// The exemption to request, see [`atc::ExemptionRequestBuilder`]
+    request: &'a ExemptionRequest,
+}
+
+impl Operation for RequestExemption<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ExemptionStatus>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/checkexemptions".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        QueryParameters::default()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/atc.xmpt.v1+xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
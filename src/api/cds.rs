@@ -0,0 +1,263 @@
+/// Operations to read CDS sources, i.e. data definitions, metadata extensions and access
+/// controls, and to analyse the dependencies of data definitions.
+///
+/// Modifications go through [`crate::api::object::UpdateSourceCode`] using
+/// [`CdsSourceKind::source_object`] while the source is locked, activation through
+/// [`crate::api::activation::Activate`] using [`CdsSourceKind::activation_object`].
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::{
+    QueryParameters,
+    api::{headers::cache_headers, object::SourceCodeObject},
+    models::{
+        activation::ActivationObject,
+        adtcore::{self, ObjectReferences},
+        cds::{self, CdsSourceMetadata},
+    },
+    operation::{Operation, Stateless},
+    response::{CacheControlled, Plain, Success},
+};
+
+/// The kinds of CDS sources.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CdsSourceKind {
+    /// Data definitions (DDLS), e.g. CDS view entities
+    #[default]
+    DataDefinition,
+    /// Metadata extensions (DDLX), i.e. the UI annotations of a view
+    MetadataExtension,
+    /// Access controls (DCLS), i.e. the authorization checks of a view
+    AccessControl,
+}
+
+impl CdsSourceKind {
+    /// The object as modified through [`crate::api::object::UpdateSourceCode`]
+    pub fn source_object<'a>(&self, name: impl Into<Cow<'a, str>>) -> SourceCodeObject<'a> {
+        match self {
+            Self::DataDefinition => SourceCodeObject::DataDefinition(name.into()),
+            Self::MetadataExtension => SourceCodeObject::MetadataExtension(name.into()),
+            Self::AccessControl => SourceCodeObject::AccessControl(name.into()),
+        }
+    }
+
+    /// The object as activated through [`crate::api::activation::Activate`]
+    pub fn activation_object(&self, name: &str) -> ActivationObject {
+        ActivationObject::new(
+            self.source_object(name.to_lowercase()).object_uri(),
+            name.to_uppercase(),
+        )
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            Self::DataDefinition => "application/vnd.sap.adt.ddlSource+xml, application/xml",
+            Self::MetadataExtension => "application/vnd.sap.adt.ddic.ddlx.v1+xml, application/xml",
+            Self::AccessControl => "application/vnd.sap.adt.acm.dclsource+xml, application/xml",
+        }
+    }
+}
+
+/// Reads the metadata of a CDS source.
+///
+/// Operation `/sap/bc/adt/ddic/ddl/sources/{name}`, `/sap/bc/adt/ddic/ddlx/sources/{name}`
+/// or `/sap/bc/adt/acm/dcl/sources/{name}`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CdsSource<'a> {
+    /// The kind of the source, a data definition by default.
+    #[builder(default)]
+    kind: CdsSourceKind,
+
+    /// The name of the source, for example `zi_travel`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the source used for caching purposes.
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for CdsSource<'_> {
+    type Response = CacheControlled<CdsSourceMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.kind
+            .source_object(self.name.as_ref())
+            .object_uri()
+            .into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            self.kind.content_type(),
+        ))
+    }
+}
+
+/// Reads the source code of a CDS source.
+///
+/// Operation `/sap/bc/adt/ddic/ddl/sources/{name}/source/main`, the same for metadata
+/// extensions and access controls.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CdsSourceCode<'a> {
+    /// The kind of the source, a data definition by default.
+    #[builder(default)]
+    kind: CdsSourceKind,
+
+    /// The name of the source, for example `zi_travel`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source code, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the source, see [`CdsSourceMetadata::source_etag`]
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for CdsSourceCode<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.kind
+            .source_object(self.name.as_ref())
+            .source_code_uri()
+            .into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Determines the entities a data definition depends on, e.g. the views and tables it
+/// selects from, including their own dependencies.
+///
+/// Operation `/sap/bc/adt/ddic/ddl/dependencies/graphdata?ddlsourceName={name}`
+#[derive(Debug, Builder)]
+pub struct DependencyGraph<'a> {
+    /// The name of the data definition, for example `zi_travel`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for DependencyGraph<'_> {
+    type Response = Success<cds::DependencyGraph>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "ddic/ddl/dependencies/graphdata".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("ddlsourceName", self.name.to_uppercase());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+}
+
+/// Lists the objects built upon a data definition, e.g. its metadata extensions, access
+/// controls and behavior definitions.
+///
+/// Operation `/sap/bc/adt/ddic/ddl/relatedObjects?ddlsourceName={name}`
+#[derive(Debug, Builder)]
+pub struct RelatedObjects<'a> {
+    /// The name of the data definition, for example `zi_travel`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for RelatedObjects<'_> {
+    type Response = Success<ObjectReferences>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "ddic/ddl/relatedObjects".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("ddlsourceName", self.name.to_uppercase());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_map_to_their_endpoints() {
+        let op = CdsSourceCodeBuilder::default()
+            .kind(CdsSourceKind::MetadataExtension)
+            .name("zc_travel")
+            .build()
+            .unwrap();
+        assert_eq!(
+            op.url(),
+            "/sap/bc/adt/ddic/ddlx/sources/zc_travel/source/main"
+        );
+
+        let op = CdsSourceBuilder::default()
+            .kind(CdsSourceKind::AccessControl)
+            .name("zi_travel")
+            .build()
+            .unwrap();
+        assert_eq!(op.url(), "/sap/bc/adt/acm/dcl/sources/zi_travel");
+
+        let op = CdsSourceBuilder::default()
+            .name("zi_travel")
+            .build()
+            .unwrap();
+        assert_eq!(op.url(), "/sap/bc/adt/ddic/ddl/sources/zi_travel");
+    }
+}
@@ -3,14 +3,22 @@ use http::{HeaderMap, HeaderValue, header};
 use std::borrow::Cow;
 
 use crate::QueryParameters;
-use crate::models::checkrun::{ObjectList, Reports};
+use crate::models::checkrun::{self, ObjectList, Reports};
+use crate::models::serialize::IntoXmlRoot;
 use crate::operation::{Operation, Stateless};
 use crate::response::Success;
 
+/// Runs the checks of a reporter on the given objects, e.g. a syntax check.
+///
+/// Objects are checked in the given version, unless unsaved content is passed along
+/// as [`checkrun::Artifact`].
+///
+/// Operation `/sap/bc/adt/checkruns?reporters={reporter}`
 #[derive(Builder, Debug, Clone)]
 pub struct RunCheck<'a> {
     objects: ObjectList,
 
+    /// The name of the reporter, e.g. `abapCheckRun`, see [`Reporters`]
     #[builder(setter(into))]
     reporter: Cow<'a, str>,
 }
@@ -37,6 +45,41 @@ impl<'a> Operation for RunCheck<'a> {
             header::CONTENT_TYPE,
             HeaderValue::from_static("application/vnd.sap.adt.checkobjects+xml"),
         );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.checkmessages+xml"),
+        );
+
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.objects.into_xml_root())
+    }
+}
+
+/// Lists the available reporters along with the object types they support.
+///
+/// Operation `/sap/bc/adt/checkruns/reporters`
+#[derive(Builder, Debug, Default)]
+pub struct Reporters {}
+
+impl Operation for Reporters {
+    type Response = Success<checkrun::Reporters>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "checkruns/reporters".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.reporters+xml"),
+        );
 
         Some(headers)
     }
@@ -0,0 +1,180 @@
+/// Operations to read global classes and the source code of their includes.
+///
+/// Modifications go through [`crate::api::object::UpdateSourceCode`] using
+/// [`crate::api::object::SourceCodeObject::ClassInclude`] while the class is locked.
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::{CacheControlled, Plain, Success};
+use crate::{
+    QueryParameters,
+    api::headers::cache_headers,
+    models::{
+        adtcore,
+        atom::VersionFeed,
+        class::{ClassInclude, ClassMetadata},
+    },
+};
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Class<'a> {
+    /// The name of the class, for example `zcl_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the class to get the data of, see [`adtcore::Version`]
+    /// If not specified in the query, the inactive version is the default if one exists.
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the class used for caching purposes.
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for Class<'_> {
+    type Response = CacheControlled<ClassMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/classes/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.oo.classes.v4+xml",
+        ))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClassSource<'a> {
+    /// The name of the class, for example `zcl_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The include to get the source code of, defaults to [`ClassInclude::Main`]
+    #[builder(default)]
+    include: ClassInclude,
+
+    /// The version of the include to get the source code of, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the include, see [`crate::models::class::ClassIncludeMetadata::etag`]
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for ClassSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/classes/{}/{}", self.name, self.include.source_uri()).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClassVersions<'a> {
+    /// The name of the class, for example `zcl_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The include to get the versions of, defaults to [`ClassInclude::Main`]
+    #[builder(default)]
+    include: ClassInclude,
+}
+
+impl Operation for ClassVersions<'_> {
+    type Response = Success<VersionFeed>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "oo/classes/{}/{}/versions",
+            self.name,
+            self.include.source_uri()
+        )
+        .into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/atom+xml;type=feed"),
+        );
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_query_defaults_to_main_include() {
+        let op = ClassSourceBuilder::default()
+            .name("ZCL_DEMO")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "oo/classes/ZCL_DEMO/source/main");
+    }
+
+    #[test]
+    fn versions_of_include_are_queried() {
+        let op = ClassVersionsBuilder::default()
+            .name("ZCL_DEMO")
+            .include(ClassInclude::TestClasses)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.url(),
+            "oo/classes/ZCL_DEMO/includes/testclasses/versions"
+        );
+    }
+
+    #[test]
+    fn class_data_query_name_is_mandatory() {
+        let result = ClassBuilder::default()
+            .version(adtcore::Version::Active)
+            .build();
+
+        assert!(result.is_err(), "Name should not be optional");
+    }
+}
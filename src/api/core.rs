@@ -1,7 +1,12 @@
-use crate::models::discovery;
+use crate::models::{adtcomp::TemplateLink, discovery};
 use crate::operation::{Operation, Stateless};
 use crate::response::Success;
+use crate::uritemplate::{TemplateValue, TemplateVariables};
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use serde::de::DeserializeOwned;
 use std::borrow::Cow;
+use std::marker::PhantomData;
 
 pub struct CoreDiscovery {}
 
@@ -16,3 +21,75 @@ impl Operation for CoreDiscovery {
         "core/discovery".into()
     }
 }
+
+/// Follows a [`TemplateLink`] by expanding its template with the provided variables.
+///
+/// The response is deserialized into `T`, which must be a model for the content type
+/// advertised by the link, e.g. [`crate::models::nameditem::NamedItemList`] for
+/// `application/vnd.sap.adt.nameditems.v1+xml`.
+///
+/// ## Example:
+/// ```ignore
+/// let op = FollowTemplateLinkBuilder::<NamedItemList>::default()
+///     .link(facet.values_uri.as_ref().unwrap())
+///     .variable("name", "DEV*")
+///     .build()?;
+/// ```
+#[derive(Debug, Builder)]
+#[builder(pattern = "owned", build_fn(validate = "Self::validate"))]
+pub struct FollowTemplateLink<'a, T> {
+    /// The link to follow, its template must be a valid RFC 6570 URI Template.
+    link: &'a TemplateLink,
+
+    /// The values of the template variables, unset variables are left out of the URI.
+    #[builder(setter(custom), default)]
+    variables: TemplateVariables<'a>,
+
+    #[builder(setter(skip))]
+    response: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> FollowTemplateLinkBuilder<'a, T> {
+    /// Sets the value of a variable in the template of the link.
+    pub fn variable<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<TemplateValue<'a>>,
+    {
+        self.variables
+            .get_or_insert_with(TemplateVariables::new)
+            .set(name, value);
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self.link {
+            Some(link) => link.uri_template().map(|_| ()).map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Operation for FollowTemplateLink<'_, T>
+where
+    T: DeserializeOwned + Send,
+{
+    type Kind = Stateless;
+
+    type Response = Success<T>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        // The template was already validated when the operation was built.
+        self.link.expand(&self.variables).unwrap().into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        if let Ok(value) = HeaderValue::from_str(&self.link.content_type) {
+            headers.insert(header::ACCEPT, value);
+        }
+        Some(headers)
+    }
+}
@@ -0,0 +1,554 @@
+/// Operations of the Change and Transport System (CTS): transport requests, their tasks
+/// and objects, transport checks and the release of requests.
+///
+/// Requests and tasks share the same operations, e.g. a task is released by passing its
+/// number to [`ReleaseTransport`] before the request itself is released.
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    Client, QueryParameters, RequestDispatch,
+    dispatch::StatelessDispatch,
+    error::{OperationError, TransportMismatch, TransportRequired},
+    models::{
+        asx::{AsxData, LockResult},
+        cts::{
+            NewTransportRequest, ObjectEntry, RequestType, TransportCheckRequest,
+            TransportCheckResult, TransportOrganizer, UserAction,
+        },
+        serialize::IntoXmlRoot,
+        tpr::TransportStatus,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+const ORGANIZER_CONTENT_TYPE: &str = "application/vnd.sap.adt.transportorganizer.v1+xml";
+
+fn organizer_headers() -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(ORGANIZER_CONTENT_TYPE),
+    );
+    headers.insert(
+        header::ACCEPT,
+        HeaderValue::from_static(ORGANIZER_CONTENT_TYPE),
+    );
+    Some(headers)
+}
+
+/// Lists the transport requests and tasks of a user, grouped by category and target.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests?user={user}&targets=true`
+#[derive(Builder, Debug, Default)]
+#[builder(setter(strip_option))]
+pub struct UserTransports<'a> {
+    /// The owner of the requests, the logged on user if not provided.
+    #[builder(setter(into), default)]
+    user: Option<Cow<'a, str>>,
+
+    /// Restricts the requests to the given statuses, e.g. [`TransportStatus::Modifiable`]
+    #[builder(setter(each(name = "status")), default)]
+    statuses: Vec<TransportStatus>,
+
+    /// Restricts the requests to the given type.
+    #[builder(default)]
+    request_type: Option<RequestType>,
+}
+
+impl Operation for UserTransports<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportrequests".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("user", self.user.as_ref());
+        params.push("targets", true);
+        params.extend(
+            self.statuses
+                .iter()
+                .map(|status| ("requestStatus", status.clone())),
+        );
+        params.push_opt("requestType", self.request_type);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.transportorganizertree.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Reads a transport request or task along with its objects.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}`
+#[derive(Builder, Debug)]
+pub struct TransportRequest<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    #[builder(setter(into))]
+    number: Cow<'a, str>,
+}
+
+impl Operation for TransportRequest<'_> {
+    const METHOD: http::Method = http::Method::GET;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(ORGANIZER_CONTENT_TYPE),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a transport request, the response contains the new request.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests`
+#[derive(Builder, Debug)]
+pub struct CreateTransport<'a> {
+    /// The request to create, see [`crate::models::cts::NewTransportRequestBuilder`]
+    request: &'a NewTransportRequest,
+}
+
+impl Operation for CreateTransport<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportrequests".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+/// Adds a task for a user to an existing transport request.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}/tasks`
+#[derive(Builder, Debug)]
+#[builder(setter(into))]
+pub struct CreateTask<'a> {
+    /// The number of the request, e.g. `A4HK900089`
+    number: Cow<'a, str>,
+
+    /// The user the task is created for.
+    owner: Cow<'a, str>,
+}
+
+impl Operation for CreateTask<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}/tasks", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::new_task(&self.number, &self.owner).into_xml_root())
+    }
+}
+
+/// Records objects in a transport request or task.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}`
+#[derive(Builder, Debug)]
+pub struct AddObjects<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    #[builder(setter(into))]
+    number: Cow<'a, str>,
+
+    /// The objects to record, see [`crate::models::cts::ObjectEntryBuilder`]
+    #[builder(setter(each(name = "object")))]
+    objects: Vec<ObjectEntry>,
+}
+
+impl Operation for AddObjects<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::add_objects(&self.number, &self.objects).into_xml_root())
+    }
+}
+
+/// Hands a transport request or task over to another user.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}`
+#[derive(Builder, Debug)]
+#[builder(setter(into))]
+pub struct ChangeOwner<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    number: Cow<'a, str>,
+
+    /// The new owner.
+    owner: Cow<'a, str>,
+}
+
+impl Operation for ChangeOwner<'_> {
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::change_owner(&self.number, &self.owner).into_xml_root())
+    }
+}
+
+/// Changes the description of a transport request or task.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}`
+#[derive(Builder, Debug)]
+#[builder(setter(into))]
+pub struct ChangeDescription<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    number: Cow<'a, str>,
+
+    /// The new description.
+    description: Cow<'a, str>,
+}
+
+impl Operation for ChangeDescription<'_> {
+    const METHOD: http::Method = http::Method::PUT;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::change_description(&self.number, &self.description).into_xml_root())
+    }
+}
+
+/// Runs the transport checks of an object, i.e. determines whether changes have to be
+/// recorded and which requests are suitable or already lock the object.
+///
+/// Operation `/sap/bc/adt/cts/transportchecks`
+#[derive(Builder, Debug)]
+pub struct TransportChecks<'a> {
+    /// The object to check, see [`crate::models::cts::TransportCheckRequestBuilder`]
+    request: &'a TransportCheckRequest,
+}
+
+impl Operation for TransportChecks<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<AsxData<TransportCheckResult>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportchecks".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=UTF-8; dataname=com.sap.adt.transport.service.checkData",
+            ),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=UTF-8; dataname=com.sap.adt.transport.service.checkData",
+            ),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+/// Releases a transport request or task, the response contains the release reports.
+///
+/// The tasks of a request have to be released before the request itself.
+///
+/// Operation `/sap/bc/adt/cts/transportrequests/{number}/newreleasejobs`
+#[derive(Builder, Debug)]
+pub struct ReleaseTransport<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    #[builder(setter(into))]
+    number: Cow<'a, str>,
+}
+
+impl Operation for ReleaseTransport<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TransportOrganizer>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}/newreleasejobs", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        organizer_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::release(&self.number).into_xml_root())
+    }
+}
+
+/// Determines the transport request a change of a locked object has to be recorded in.
+///
+/// Local objects need no request, objects already locked in a request have to be changed
+/// in that one. Otherwise the transport checks are run through [`TransportChecks`] to find
+/// the request that locks the object, or to verify that the given request is one the
+/// change can be recorded in.
+///
+/// Fails with [`TransportMismatch`] if the object is locked in another request than the given
+/// one, and with [`TransportRequired`] if a request is needed but none or an unusable one was
+/// given.
+pub async fn transport_for<T>(
+    client: &Client<T>,
+    object_uri: &str,
+    lock: &LockResult,
+    transport: Option<&str>,
+) -> Result<Option<String>, OperationError>
+where
+    T: RequestDispatch,
+{
+    if lock.is_local {
+        return Ok(None);
+    }
+
+    let uri = if object_uri.starts_with('/') {
+        object_uri.to_owned()
+    } else {
+        format!("/sap/bc/adt/{object_uri}")
+    };
+    if let Some(locked_in) = lock.transport() {
+        return Ok(locked_transport(uri, locked_in, transport)?);
+    }
+
+    let request = TransportCheckRequest::for_uri(uri.as_str());
+    let result = TransportChecks { request: &request }
+        .dispatch(client)
+        .await?;
+
+    if result.body().is_local() {
+        return Ok(None);
+    }
+    if let Some(header) = result.body().locked_in() {
+        return Ok(locked_transport(uri, &header.number, transport)?);
+    }
+
+    let candidates: Vec<_> = result
+        .body()
+        .requests()
+        .filter(|request| request.status.is_modifiable())
+        .map(|request| request.number.clone())
+        .collect();
+    match transport {
+        Some(requested)
+            if candidates
+                .iter()
+                .any(|number| number.eq_ignore_ascii_case(requested)) =>
+        {
+            Ok(Some(requested.to_owned()))
+        }
+        _ => Err(TransportRequired { uri, candidates }.into()),
+    }
+}
+
+/// The request of an object that is locked in `locked_in`, the given one has to match it.
+fn locked_transport(
+    uri: String,
+    locked_in: &str,
+    transport: Option<&str>,
+) -> Result<Option<String>, TransportMismatch> {
+    match transport {
+        Some(requested) if !requested.eq_ignore_ascii_case(locked_in) => Err(TransportMismatch {
+            uri,
+            requested: requested.to_owned(),
+            locked_in: locked_in.to_owned(),
+        }),
+        _ => Ok(Some(locked_in.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::asx::AsxData, testing::FakeSystem};
+    use http::Method;
+
+    fn lock_in(transport: &str) -> LockResult {
+        let plain = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <LOCK_HANDLE>2E84CAE23E14D343E405FC48FDBBFB3B28932EC4</LOCK_HANDLE>
+                        <CORRNR>{transport}</CORRNR>
+                        <CORRUSER>DEVELOPER</CORRUSER>
+                        <CORRTEXT>Test Transport</CORRTEXT>
+                        <IS_LOCAL/>
+                        <IS_LINK_UP/>
+                        <MODIFICATION_SUPPORT>ModificationsLoggedOnly</MODIFICATION_SUPPORT>
+                        <SCOPE_MESSAGES/>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#
+        );
+        serde_xml_rs::from_str::<AsxData<LockResult>>(&plain)
+            .unwrap()
+            .inner()
+    }
+
+    #[tokio::test]
+    async fn given_transport_has_to_match_the_lock() {
+        let client = FakeSystem::offline().client();
+        let lock = lock_in("A4HK900089");
+
+        let result = transport_for(
+            &client,
+            "programs/programs/zdemo",
+            &lock,
+            Some("A4HK900090"),
+        );
+        let Err(OperationError::TransportMismatch(err)) = result.await else {
+            panic!("Expected the transports to mismatch.");
+        };
+        assert_eq!(err.uri, "/sap/bc/adt/programs/programs/zdemo");
+        assert_eq!(err.locked_in, "A4HK900089");
+
+        let result = transport_for(
+            &client,
+            "programs/programs/zdemo",
+            &lock,
+            Some("A4HK900089"),
+        );
+        assert_eq!(result.await.unwrap().as_deref(), Some("A4HK900089"));
+
+        let result = transport_for(&client, "programs/programs/zdemo", &lock, None);
+        assert_eq!(result.await.unwrap().as_deref(), Some("A4HK900089"));
+    }
+
+    #[tokio::test]
+    async fn given_transport_has_to_be_a_candidate() {
+        let system = FakeSystem::responding(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <PGMID>R3TR</PGMID>
+                        <OBJECT>PROG</OBJECT>
+                        <OBJECTNAME>ZDEMO</OBJECTNAME>
+                        <OPERATION>I</OPERATION>
+                        <DEVCLASS>ZDEMO</DEVCLASS>
+                        <CTEXT>Demo</CTEXT>
+                        <KORRFLAG>X</KORRFLAG>
+                        <AS4USER>DEVELOPER</AS4USER>
+                        <PDEVCLASS/>
+                        <DLVUNIT>HOME</DLVUNIT>
+                        <NAMESPACE>/0CUST/</NAMESPACE>
+                        <RESULT>S</RESULT>
+                        <RECORDING>X</RECORDING>
+                        <EXISTING_REQ_ONLY/>
+                        <REQUESTS>
+                            <CTS_REQUEST>
+                                <REQ_HEADER>
+                                    <TRKORR>A4HK900089</TRKORR>
+                                    <TRFUNCTION>K</TRFUNCTION>
+                                    <TRSTATUS>D</TRSTATUS>
+                                    <TARSYSTEM/>
+                                    <AS4USER>DEVELOPER</AS4USER>
+                                    <AS4DATE>2025-01-10</AS4DATE>
+                                    <AS4TIME>10:00:00</AS4TIME>
+                                    <AS4TEXT>Demo changes</AS4TEXT>
+                                    <CLIENT>001</CLIENT>
+                                </REQ_HEADER>
+                            </CTS_REQUEST>
+                        </REQUESTS>
+                        <LOCKS/>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#,
+        );
+        let client = system.client();
+        let lock = lock_in("");
+
+        let result = transport_for(
+            &client,
+            "programs/programs/zdemo",
+            &lock,
+            Some("A4HK900090"),
+        );
+        let Err(OperationError::TransportRequired(err)) = result.await else {
+            panic!("Expected a foreign transport to be rejected.");
+        };
+        assert_eq!(err.candidates, ["A4HK900089"]);
+        assert!(system.requested(Method::POST, "/cts/transportchecks"));
+
+        let result = transport_for(
+            &client,
+            "programs/programs/zdemo",
+            &lock,
+            Some("A4HK900089"),
+        );
+        assert_eq!(result.await.unwrap().as_deref(), Some("A4HK900089"));
+    }
+}
@@ -0,0 +1,271 @@
+/// Operations to preview the data of the system, e.g. to run a query against a table.
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::datapreview::TableData,
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+fn preview_headers() -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    headers.insert(
+        header::ACCEPT,
+        HeaderValue::from_static(
+            "application/xml, application/vnd.sap.adt.datapreview.table.v1+xml",
+        ),
+    );
+    Some(headers)
+}
+
+/// Runs an ABAP SQL `SELECT` statement and returns the first rows of its result.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::datapreview::FreestyleQueryBuilder;
+///
+/// FreestyleQueryBuilder::default()
+///     .query("SELECT carrid, connid, fldate, price FROM sflight WHERE carrid = 'LH'")
+///     .row_limit(50)
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Operation `/sap/bc/adt/datapreview/freestyle?rowNumber={row_limit}`
+#[derive(Builder, Debug)]
+pub struct FreestyleQuery<'a> {
+    /// The `SELECT` statement, without a trailing period.
+    #[builder(setter(into))]
+    query: Cow<'a, str>,
+
+    /// The maximum number of returned rows, 100 by default.
+    #[builder(default = 100)]
+    row_limit: u64,
+}
+
+impl Operation for FreestyleQuery<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TableData>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "datapreview/freestyle".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("rowNumber", self.row_limit);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        preview_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.query.clone().into_owned()))
+    }
+}
+
+/// The kind of entity whose data is previewed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EntityKind {
+    /// A table or view of the ABAP Dictionary, e.g. `SFLIGHT`
+    #[default]
+    Ddic,
+    /// A CDS view entity, e.g. `I_COUNTRY`
+    Cds,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ddic => "ddic",
+            Self::Cds => "cds",
+        }
+    }
+}
+
+/// The direction to sort a column in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// A column to sort the result by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy<'a> {
+    column: Cow<'a, str>,
+    order: SortOrder,
+}
+
+impl<'a> OrderBy<'a> {
+    pub fn ascending(column: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            column: column.into(),
+            order: SortOrder::Ascending,
+        }
+    }
+
+    pub fn descending(column: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            column: column.into(),
+            order: SortOrder::Descending,
+        }
+    }
+}
+
+/// Returns the first rows of a DDIC table or a CDS view.
+///
+/// The query is built from the given columns, filter and ordering - all columns are selected
+/// if none are given.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::datapreview::{EntityKind, EntityPreviewBuilder, OrderBy};
+///
+/// EntityPreviewBuilder::default()
+///     .entity("SFLIGHT")
+///     .column("CARRID")
+///     .column("FLDATE")
+///     .filter("carrid = 'LH'")
+///     .order_by(OrderBy::descending("FLDATE"))
+///     .row_limit(10)
+///     .build()
+///     .unwrap();
+///
+/// EntityPreviewBuilder::default()
+///     .kind(EntityKind::Cds)
+///     .entity("I_COUNTRY")
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Operation `/sap/bc/adt/datapreview/{ddic|cds}?rowNumber={row_limit}&ddicEntityName={entity}`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct EntityPreview<'a> {
+    /// The name of the table or the CDS view, e.g. `SFLIGHT`
+    #[builder(setter(into))]
+    entity: Cow<'a, str>,
+
+    /// Whether the entity is a DDIC table or a CDS view, a DDIC table by default.
+    #[builder(default)]
+    kind: EntityKind,
+
+    /// The selected columns, all if none are given.
+    #[builder(setter(each(name = "column", into)), default)]
+    columns: Vec<Cow<'a, str>>,
+
+    /// The condition of the `WHERE` clause, e.g. `carrid = 'LH' AND seatsocc > 100`
+    #[builder(setter(into), default)]
+    filter: Option<Cow<'a, str>>,
+
+    /// The columns to sort the result by, in the given order.
+    #[builder(setter(each(name = "order_by")), default)]
+    ordering: Vec<OrderBy<'a>>,
+
+    /// The maximum number of returned rows, 100 by default.
+    #[builder(default = 100)]
+    row_limit: u64,
+}
+
+impl EntityPreview<'_> {
+    /// Returns the `SELECT` statement the data is previewed with.
+    pub fn statement(&self) -> String {
+        let columns = if self.columns.is_empty() {
+            "*".to_string()
+        } else {
+            self.columns.join(", ")
+        };
+
+        let mut statement = format!("SELECT {columns} FROM {}", self.entity);
+        if let Some(filter) = &self.filter {
+            statement.push_str(&format!(" WHERE {filter}"));
+        }
+        if !self.ordering.is_empty() {
+            let ordering: Vec<_> = self
+                .ordering
+                .iter()
+                .map(|order_by| match order_by.order {
+                    SortOrder::Ascending => format!("{} ASCENDING", order_by.column),
+                    SortOrder::Descending => format!("{} DESCENDING", order_by.column),
+                })
+                .collect();
+            statement.push_str(&format!(" ORDER BY {}", ordering.join(", ")));
+        }
+        statement
+    }
+}
+
+impl Operation for EntityPreview<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<TableData>;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("datapreview/{}", self.kind.as_str()).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("rowNumber", self.row_limit);
+        params.push("ddicEntityName", self.entity.as_ref());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        preview_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.statement()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_selects_all_columns_by_default() {
+        let op = EntityPreviewBuilder::default()
+            .kind(EntityKind::Cds)
+            .entity("I_COUNTRY")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.statement(), "SELECT * FROM I_COUNTRY");
+        assert_eq!(op.url(), "datapreview/cds");
+    }
+
+    #[test]
+    fn statement_contains_filter_and_ordering() {
+        let op = EntityPreviewBuilder::default()
+            .entity("SFLIGHT")
+            .column("CARRID")
+            .column("FLDATE")
+            .filter("carrid = 'LH'")
+            .order_by(OrderBy::descending("FLDATE"))
+            .order_by(OrderBy::ascending("CARRID"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.statement(),
+            "SELECT CARRID, FLDATE FROM SFLIGHT WHERE carrid = 'LH' ORDER BY FLDATE DESCENDING, CARRID ASCENDING"
+        );
+        assert_eq!(op.url(), "datapreview/ddic");
+    }
+}
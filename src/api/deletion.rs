@@ -0,0 +1,101 @@
+/// Operations to check whether objects can be deleted and to delete them.
+///
+/// Both operations accept any number of objects, see [`DeletionRequest`].
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::{
+        deletion::{DeletionCheckResult, DeletionRequest, DeletionResult},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateful, Stateless},
+    response::Success,
+};
+
+const DELETION_REQUEST_TYPE: &str = "application/vnd.sap.adt.deletion.request.v1+xml";
+
+/// Checks whether objects can be deleted without deleting them.
+///
+/// Operation `/sap/bc/adt/deletion/check`
+#[derive(Builder, Debug)]
+pub struct CheckDeletion<'a> {
+    request: &'a DeletionRequest,
+}
+
+impl Operation for CheckDeletion<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<DeletionCheckResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "deletion/check".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        QueryParameters::default()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(DELETION_REQUEST_TYPE),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.deletion.check.response.v1+xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+/// Deletes objects, the result has to be checked for objects that could not be deleted.
+///
+/// Dispatched in a user session, so objects locked through [`crate::api::object::Lock`]
+/// beforehand can be deleted by passing along their lock handle.
+///
+/// Operation `/sap/bc/adt/deletion/delete`
+#[derive(Builder, Debug)]
+pub struct DeleteObjects<'a> {
+    request: &'a DeletionRequest,
+}
+
+impl Operation for DeleteObjects<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateful;
+    type Response = Success<DeletionResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "deletion/delete".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        QueryParameters::default()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(DELETION_REQUEST_TYPE),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.deletion.response.v1+xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
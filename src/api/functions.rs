@@ -0,0 +1,403 @@
+/// Operations to read function groups, their includes and function modules.
+///
+/// Modifications go through [`crate::api::object::UpdateSourceCode`] while the function
+/// group is locked, see [`crate::api::object::SourceCodeObject::lock_uri`].
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::{CacheControlled, Plain, Success};
+use crate::{
+    QueryParameters,
+    api::headers::cache_headers,
+    models::{
+        adtcore,
+        asx::{AsxData, NodeStructure},
+        function::{
+            FunctionGroupIncludeMetadata, FunctionGroupMetadata, FunctionModuleMetadata,
+            FunctionSignature,
+        },
+    },
+};
+
+/// Object type of function modules within a [`NodeStructure`]
+pub const FUNCTION_MODULE_TYPE: &str = "FUGR/FF";
+
+/// Object type of function group includes within a [`NodeStructure`]
+pub const FUNCTION_GROUP_INCLUDE_TYPE: &str = "FUGR/I";
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroup<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the function group to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the function group used for caching purposes.
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionGroup<'_> {
+    type Response = CacheControlled<FunctionGroupMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.groups.v3+xml",
+        ))
+    }
+}
+
+/// Fetches the source code of the main program of a function group, e.g. `SAPLZDEMO`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupSource<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source code, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionGroupSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/source/main", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Lists the function modules and includes of a function group.
+///
+/// Use [`NodeStructure::nodes_of_type`] with [`FUNCTION_MODULE_TYPE`] or
+/// [`FUNCTION_GROUP_INCLUDE_TYPE`] to distinguish them.
+///
+/// Operation `/sap/bc/adt/repository/nodestructure`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupContents<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for FunctionGroupContents<'_> {
+    type Response = Success<AsxData<NodeStructure>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/nodestructure".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("parent_type", "FUGR/F");
+        params.push("parent_name", &self.name);
+        params.push("withShortDescriptions", true);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=utf-8; dataname=com.sap.adt.RepositoryObjectTreeContent",
+            ),
+        );
+        Some(headers)
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionModule<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the function module, for example `z_demo_read`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the function module to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionModule<'_> {
+    type Response = CacheControlled<FunctionModuleMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/fmodules/{}", self.group, self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.fmodules.v3+xml",
+        ))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionModuleSource<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the function module, for example `z_demo_read`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source code, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionModuleSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "functions/groups/{}/fmodules/{}/source/main",
+            self.group, self.name
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Fetches the parameters and exceptions of a function module.
+///
+/// The signature is part of the source code, which is parsed into a [`FunctionSignature`].
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionModuleParameters<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the function module, for example `z_demo_read`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source code, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionModuleParameters<'_> {
+    type Response = CacheControlled<FunctionSignature>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "functions/groups/{}/fmodules/{}/source/main",
+            self.group, self.name
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupInclude<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the include, for example `lzdemotop`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the include to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionGroupInclude<'_> {
+    type Response = CacheControlled<FunctionGroupIncludeMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/includes/{}", self.group, self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.fincludes.v2+xml",
+        ))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupIncludeSource<'a> {
+    /// The name of the function group, for example `zdemo`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the include, for example `lzdemotop`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source code, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionGroupIncludeSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "functions/groups/{}/includes/{}/source/main",
+            self.group, self.name
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::object::SourceCodeObject;
+
+    #[test]
+    fn function_module_is_locked_through_group() {
+        let object = SourceCodeObject::FunctionModule("ZDEMO".into(), "Z_DEMO_READ".into());
+
+        assert_eq!(object.lock_uri(), "/sap/bc/adt/functions/groups/ZDEMO");
+        assert_eq!(
+            object.source_code_uri(),
+            "/sap/bc/adt/functions/groups/ZDEMO/fmodules/Z_DEMO_READ/source/main"
+        );
+    }
+
+    #[test]
+    fn function_module_query_group_is_mandatory() {
+        let result = FunctionModuleBuilder::default().name("Z_DEMO_READ").build();
+
+        assert!(result.is_err(), "Group should not be optional");
+    }
+}
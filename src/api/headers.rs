@@ -0,0 +1,30 @@
+/// Headers shared by the operations of several object types.
+use http::{HeaderMap, HeaderValue, header};
+
+/// Headers need to handle whether we have a cached version locally and provide the ETag.
+///
+/// An etag that is not a valid header value is skipped, the resource is fetched uncached then.
+pub(crate) fn cache_headers(etag: Option<&str>, accept: &'static str) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    match etag.and_then(|etag| HeaderValue::from_str(etag).ok()) {
+        None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        Some(etag) => map.insert(header::IF_NONE_MATCH, etag),
+    };
+    map.insert(header::ACCEPT, HeaderValue::from_static(accept));
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_etag_is_skipped() {
+        let headers = cache_headers(Some("2025\n0830"), "text/plain");
+        assert!(headers.get(header::IF_NONE_MATCH).is_none());
+        assert_eq!(headers[header::CACHE_CONTROL], "no-cache");
+
+        let headers = cache_headers(Some("202508302149440011"), "text/plain");
+        assert_eq!(headers[header::IF_NONE_MATCH], "202508302149440011");
+    }
+}
@@ -0,0 +1,166 @@
+/// Operations to read include programs and the main programs they are used in.
+///
+/// The source code of an include is only meaningful within a main program, reads accept
+/// the URI of that main program as `context`, e.g. `/sap/bc/adt/programs/programs/z_main`.
+/// Modifications go through [`crate::api::object::UpdateSourceCode`] using
+/// [`crate::api::object::SourceCodeObject::Include`] while the include is locked.
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::{CacheControlled, Plain, Success};
+use crate::{
+    QueryParameters,
+    api::headers::cache_headers,
+    models::{
+        adtcore::{self, ObjectReferences},
+        include::AbapInclude,
+    },
+};
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Include<'a> {
+    /// The name of the include, for example `zbadicheck_inc`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The URI of the main program to read the include in the context of.
+    #[builder(setter(into), default)]
+    context: Option<Cow<'a, str>>,
+
+    /// The version of the include to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the include used for caching purposes.
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for Include<'_> {
+    type Response = CacheControlled<AbapInclude>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("programs/includes/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("context", self.context.as_ref());
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.programs.includes.v2+xml",
+        ))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct IncludeSource<'a> {
+    /// The name of the include, for example `zbadicheck_inc`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The URI of the main program to read the include in the context of.
+    #[builder(setter(into), default)]
+    context: Option<Cow<'a, str>>,
+
+    /// The version of the include to get the source code of, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for IncludeSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("programs/includes/{}/source/main", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("context", self.context.as_ref());
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Fetches the main programs an include is used in, any of them is a valid `context`.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct IncludeMainPrograms<'a> {
+    /// The name of the include, for example `zbadicheck_inc`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for IncludeMainPrograms<'_> {
+    type Response = Success<ObjectReferences>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("programs/includes/{}/mainprograms", self.name).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_is_passed_as_parameter() {
+        let op = IncludeSourceBuilder::default()
+            .name("ZBADICHECK_INC")
+            .context("/sap/bc/adt/programs/programs/z_badi_check")
+            .build()
+            .unwrap();
+
+        let mut url = url::Url::parse("http://localhost/").unwrap();
+        op.parameters().add_to_url(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("context=%2Fsap%2Fbc%2Fadt%2Fprograms%2Fprograms%2Fz_badi_check")
+        );
+    }
+
+    #[test]
+    fn include_query_name_is_mandatory() {
+        let result = IncludeBuilder::default()
+            .context("/sap/bc/adt/programs/programs/z_badi_check")
+            .build();
+
+        assert!(result.is_err(), "Name should not be optional");
+    }
+}
@@ -0,0 +1,151 @@
+/// Operations to read and browse the versions of global interfaces.
+///
+/// Interfaces are created through [`crate::api::object::create_object`] from a
+/// [`crate::models::interface::NewInterface`], which validates the interface first.
+/// Modifications go through [`crate::api::object::UpdateSourceCode`] using
+/// [`crate::api::object::SourceCodeObject::Interface`] while the interface is locked.
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::{CacheControlled, Plain, Success};
+use crate::{
+    QueryParameters,
+    api::headers::cache_headers,
+    models::{adtcore, atom::VersionFeed, interface::InterfaceMetadata},
+};
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Interface<'a> {
+    /// The name of the interface, for example `zif_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the interface to get the data of, see [`adtcore::Version`]
+    /// If not specified in the query, the inactive version is the default if one exists.
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the interface used for caching purposes.
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for Interface<'_> {
+    type Response = CacheControlled<InterfaceMetadata>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/interfaces/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.oo.interfaces.v5+xml",
+        ))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct InterfaceSource<'a> {
+    /// The name of the interface, for example `zif_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the interface to get the source code of, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the source, see [`InterfaceMetadata::source_etag`]
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for InterfaceSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/interfaces/{}/source/main", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct InterfaceVersions<'a> {
+    /// The name of the interface, for example `zif_demo`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for InterfaceVersions<'_> {
+    type Response = Success<VersionFeed>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/interfaces/{}/source/main/versions", self.name).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/atom+xml;type=feed"),
+        );
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_create_source_query_with_etag() {
+        InterfaceSourceBuilder::default()
+            .name("ZIF_DEMO")
+            .version(adtcore::Version::Active)
+            .etag("202508101355580001")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn interface_data_query_name_is_mandatory() {
+        let result = InterfaceBuilder::default()
+            .version(adtcore::Version::Active)
+            .build();
+
+        assert!(result.is_err(), "Name should not be optional");
+    }
+}
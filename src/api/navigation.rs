@@ -0,0 +1,90 @@
+/// Operations to navigate from a position in the source code to the related object,
+/// e.g. to the definition of a variable or the implementation of a method.
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::{abapsource::SourceLocation, adtcore::ObjectReference},
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+/// What to navigate to from the element at the position.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NavigationFilter {
+    /// The definition of the element, e.g. the declaration of a variable.
+    #[default]
+    Definition,
+    /// The implementation of the element, e.g. of a method.
+    Implementation,
+    /// The statement matching the one at the position, e.g. `ENDIF` for `IF`.
+    MatchingStatement,
+}
+
+impl NavigationFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Definition => "definition",
+            Self::Implementation => "implementation",
+            Self::MatchingStatement => "matchingStatement",
+        }
+    }
+}
+
+/// Determines the target to navigate to from a position in the source code.
+///
+/// The position of the target is part of its URI, see [`ObjectReference::location`]
+///
+/// Operation `/sap/bc/adt/navigation/target?uri={uri}#start={line},{column}`
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct NavigationTarget<'a> {
+    /// The source and the position of the element in it, e.g. `…/zwegwerf1/source/main#start=8,4`
+    location: SourceLocation,
+
+    /// What to navigate to, the definition by default.
+    #[builder(default)]
+    filter: NavigationFilter,
+
+    /// The current, unsaved source code of the editor - the saved one is used if not provided.
+    #[builder(setter(into), default)]
+    source: Option<Cow<'a, str>>,
+}
+
+impl Operation for NavigationTarget<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ObjectReference>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "navigation/target".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params.push("filter", self.filter.as_str());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        if self.source.is_some() {
+            headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            );
+        }
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        self.source
+            .as_ref()
+            .map(|source| Ok(source.clone().into_owned()))
+    }
+}
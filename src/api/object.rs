@@ -1,4 +1,4 @@
-/// Operations to manage objects, i.e locking / unlocking...
+/// Operations to manage objects, i.e creating / locking / unlocking...
 ///
 /// This works the same for programs, includes, classes, etc..
 use derive_builder::Builder;
@@ -6,9 +6,16 @@ use http::{HeaderMap, HeaderValue, header};
 use std::borrow::Cow;
 
 use crate::{
-    QueryParameters,
-    models::asx::{self, LockResult},
-    operation::{Operation, Stateful},
+    Client, QueryParameters, RequestDispatch,
+    dispatch::StatelessDispatch,
+    error::{OperationError, ValidationFailed},
+    models::{
+        asx::{self, AsxData, LockResult, ValidationResult},
+        class::ClassInclude,
+        creation::NewObject,
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateful, Stateless},
     response::Success,
 };
 
@@ -29,26 +36,75 @@ pub enum SourceCodeObject<'a> {
     Include(Cow<'a, str>),
     GlobalClass(Cow<'a, str>),
     TestClass(Cow<'a, str>),
+    /// Any of the source code includes of a global class, see [`ClassInclude`]
+    ClassInclude(Cow<'a, str>, ClassInclude),
+    Interface(Cow<'a, str>),
+    /// The main program of a function group, e.g. `SAPLZDEMO`
+    FunctionGroup(Cow<'a, str>),
+    /// A function module given by its function group and name
+    FunctionModule(Cow<'a, str>, Cow<'a, str>),
+    /// An include of a function group given by the function group and include name
+    FunctionGroupInclude(Cow<'a, str>, Cow<'a, str>),
     Structure(Cow<'a, str>),
+    /// A CDS data definition, e.g. `ZI_TRAVEL`
+    DataDefinition(Cow<'a, str>),
+    /// A CDS metadata extension, e.g. `ZC_TRAVEL`
+    MetadataExtension(Cow<'a, str>),
+    /// A CDS access control, e.g. `ZI_TRAVEL`
+    AccessControl(Cow<'a, str>),
 }
 
 impl SourceCodeObject<'_> {
     pub fn object_uri(&self) -> String {
         match &self {
             Self::Program(name) => format!("/sap/bc/adt/programs/programs/{name}"),
-            Self::GlobalClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
+            Self::GlobalClass(name) => format!("/sap/bc/adt/oo/classes/{name}"),
             Self::Include(name) => format!("/sap/bc/adt/programs/includes/{name}"),
-            Self::TestClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
+            Self::TestClass(name) => format!("/sap/bc/adt/oo/classes/{name}"),
+            Self::ClassInclude(name, _) => format!("/sap/bc/adt/oo/classes/{name}"),
+            Self::Interface(name) => format!("/sap/bc/adt/oo/interfaces/{name}"),
+            Self::FunctionGroup(group) => format!("/sap/bc/adt/functions/groups/{group}"),
+            Self::FunctionModule(group, name) => {
+                format!("/sap/bc/adt/functions/groups/{group}/fmodules/{name}")
+            }
+            Self::FunctionGroupInclude(group, name) => {
+                format!("/sap/bc/adt/functions/groups/{group}/includes/{name}")
+            }
             Self::Structure(name) => format!("/sap/bc/adt/ddic/structures/{name}"),
+            Self::DataDefinition(name) => format!("/sap/bc/adt/ddic/ddl/sources/{name}"),
+            Self::MetadataExtension(name) => format!("/sap/bc/adt/ddic/ddlx/sources/{name}"),
+            Self::AccessControl(name) => format!("/sap/bc/adt/acm/dcl/sources/{name}"),
+        }
+    }
+
+    /// The URI of the object that has to be locked to modify the source code.
+    ///
+    /// Function modules and function group includes are locked through their function group.
+    pub fn lock_uri(&self) -> String {
+        match &self {
+            Self::FunctionModule(group, _) | Self::FunctionGroupInclude(group, _) => {
+                Self::FunctionGroup(group.clone()).object_uri()
+            }
+            _ => self.object_uri(),
         }
     }
 
     pub fn source_code_uri(&self) -> String {
         match &self {
             Self::Program(name) => format!("/sap/bc/adt/programs/programs/{name}/source/main"),
-            Self::GlobalClass(name) => format!("/sap/bc/adt/programs/includes/{name}/source/main"),
+            Self::GlobalClass(name) => format!("/sap/bc/adt/oo/classes/{name}/source/main"),
             Self::Include(name) => format!("/sap/bc/adt/programs/includes/{name}/source/main"),
-            Self::TestClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
+            Self::TestClass(name) => format!("/sap/bc/adt/oo/classes/{name}/includes/testclasses"),
+            Self::ClassInclude(name, include) => {
+                format!("/sap/bc/adt/oo/classes/{name}/{}", include.source_uri())
+            }
+            Self::Interface(name) => format!("/sap/bc/adt/oo/interfaces/{name}/source/main"),
+            Self::FunctionGroup(_)
+            | Self::FunctionModule(..)
+            | Self::FunctionGroupInclude(..)
+            | Self::DataDefinition(_)
+            | Self::MetadataExtension(_)
+            | Self::AccessControl(_) => format!("{}/source/main", self.object_uri()),
             Self::Structure(name) => format!("/sap/bc/adt/ddic/structures/{name}/source/main"),
         }
     }
@@ -166,13 +222,18 @@ impl Operation for Unlock<'_> {
 #[derive(Builder, Debug)]
 #[builder(setter(strip_option))]
 pub struct UpdateSourceCode<'a> {
-    object: SourceCodeObject<'a>,
+    pub(crate) object: SourceCodeObject<'a>,
 
     #[builder(setter(into))]
-    lock_handle: Cow<'a, str>,
+    pub(crate) lock_handle: Cow<'a, str>,
 
     #[builder(setter(into))]
-    content: Cow<'a, str>,
+    pub(crate) content: Cow<'a, str>,
+
+    /// The transport to record the change in, e.g. `A4HK900089` - required unless the
+    /// object is local, see [`crate::api::cts::transport_for`]
+    #[builder(setter(into), default)]
+    pub(crate) transport: Option<Cow<'a, str>>,
 }
 
 impl Operation for UpdateSourceCode<'_> {
@@ -188,6 +249,7 @@ impl Operation for UpdateSourceCode<'_> {
     fn parameters(&self) -> QueryParameters {
         let mut params = QueryParameters::default();
         params.push("lockHandle", &self.lock_handle);
+        params.push_opt("corrNr", self.transport.as_ref());
         params
     }
 
@@ -204,3 +266,159 @@ impl Operation for UpdateSourceCode<'_> {
         Some(Ok(self.content.clone().into_owned()))
     }
 }
+
+/// Validates the name and properties of an object before it is created.
+///
+/// Operation `/sap/bc/adt/{type}/validation`, e.g. `/sap/bc/adt/programs/validation`
+#[derive(Builder, Debug)]
+pub struct ValidateObject<'a> {
+    /// The object that is to be created.
+    object: &'a NewObject<'a>,
+}
+
+impl Operation for ValidateObject<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<AsxData<ValidationResult>>;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.object.kind().validation_uri().into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("objtype", self.object.kind().object_type());
+        params.push("objname", self.object.name());
+        params.push("packagename", self.object.package());
+        params.push("description", self.object.description());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=utf-8; dataname=com.sap.adt.validationMessages",
+            ),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a new object, objects outside of local packages require a transport.
+///
+/// The object should be checked through [`ValidateObject`] first, the server
+/// only reports the first problem it encounters during creation - [`create_object`]
+/// does both.
+#[derive(Builder, Debug)]
+#[builder(setter(strip_option))]
+pub struct CreateObject<'a> {
+    /// The object to create, see [`crate::models::creation::NewObjectBuilder`]
+    object: NewObject<'a>,
+
+    /// The transport to record the creation in, e.g. `A4HK900089`
+    #[builder(setter(into), default)]
+    transport: Option<Cow<'a, str>>,
+}
+
+impl Operation for CreateObject<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<()>;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.object.kind().collection_uri().into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("corrNr", self.transport.as_ref());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(self.object.kind().content_type()),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.object.into_xml_root())
+    }
+}
+
+/// Validates the object through [`ValidateObject`] and only creates it through
+/// [`CreateObject`] if the validation passed.
+///
+/// Fails with [`ValidationFailed`] without creating the object otherwise.
+pub async fn create_object<T>(
+    client: &Client<T>,
+    object: NewObject<'_>,
+    transport: Option<&str>,
+) -> Result<(), OperationError>
+where
+    T: RequestDispatch,
+{
+    let validation = ValidateObject { object: &object }.dispatch(client).await?;
+    if !validation.body().is_ok() {
+        return Err(ValidationFailed {
+            name: object.name().to_owned(),
+            severity: validation.body().severity.clone(),
+            message: validation.body().short_text.clone(),
+        }
+        .into());
+    }
+
+    CreateObject {
+        object,
+        transport: transport.map(Cow::Borrowed),
+    }
+    .dispatch(client)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::creation::{NewObjectBuilder, ObjectKind},
+        testing::FakeSystem,
+    };
+    use http::Method;
+
+    #[tokio::test]
+    async fn object_is_not_created_if_validation_fails() {
+        let system = FakeSystem::responding(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <SEVERITY>ERROR</SEVERITY>
+                        <SHORT_TEXT>Program Z_DEMO already exists</SHORT_TEXT>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#,
+        );
+
+        let program = NewObjectBuilder::default()
+            .kind(ObjectKind::Program)
+            .name("Z_DEMO")
+            .description("Demo Program")
+            .package("$TMP")
+            .build()
+            .unwrap();
+
+        let result = create_object(&system.client(), program, None).await;
+        assert!(matches!(result, Err(OperationError::ValidationFailed(_))));
+
+        assert!(system.requested(Method::POST, "/programs/validation"));
+        assert!(!system.requested(Method::POST, "/programs/programs"));
+    }
+}
@@ -0,0 +1,367 @@
+/// Operations to read, browse and create packages (`DEVC/K`).
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::{
+    Client, QueryParameters, RequestDispatch,
+    dispatch::StatelessDispatch,
+    error::{OperationError, ValidationFailed},
+    models::{
+        adtcore,
+        asx::{AsxData, NodeStructure, ValidationResult},
+        package::{self, NewPackage, PackageType},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+    uritemplate::encode_segment,
+};
+
+/// Fetches the metadata of a package, e.g. its super package, type and transport layer.
+///
+/// The name can be taken from any [`adtcore::PackageRef`], e.g. the one of a program.
+///
+/// Operation `/sap/bc/adt/packages/{name}`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Package<'a> {
+    /// The name of the package, for example `$TMP` or `SADT_TOOLS_CORE`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the package to get the data of, see [`adtcore::Version`]
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+}
+
+impl Operation for Package<'_> {
+    type Response = Success<package::Package>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("packages/{}", encode_segment(&self.name.to_lowercase())).into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.packages.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Fetches the content of a package, i.e. its sub packages and objects.
+///
+/// Operation `/sap/bc/adt/repository/nodestructure`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PackageContents<'a> {
+    /// The name of the package, for example `$TMP`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// Whether to include the short descriptions of the objects, defaults to `true`.
+    #[builder(default = true)]
+    short_descriptions: bool,
+}
+
+impl Operation for PackageContents<'_> {
+    type Response = Success<AsxData<NodeStructure>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/nodestructure".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("parent_type", "DEVC/K");
+        params.push("parent_name", &self.name);
+        params.push("withShortDescriptions", self.short_descriptions);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=utf-8; dataname=com.sap.adt.RepositoryObjectTreeContent",
+            ),
+        );
+        Some(headers)
+    }
+}
+
+/// Validates the properties of a package before it is created.
+///
+/// Operation `/sap/bc/adt/packages/validation`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ValidatePackage<'a> {
+    /// The name of the package to create, for example `ZMY_PACKAGE`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the package to create.
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+
+    /// The super package of the package to create.
+    #[builder(setter(into), default)]
+    super_package: Option<Cow<'a, str>>,
+
+    /// The type of the package, defaults to [`PackageType::Development`]
+    #[builder(default = PackageType::Development)]
+    package_type: PackageType,
+
+    /// The software component, e.g. `HOME` or `LOCAL`
+    #[builder(setter(into), default)]
+    software_component: Option<Cow<'a, str>>,
+
+    /// The transport layer, not required for local packages.
+    #[builder(setter(into), default)]
+    transport_layer: Option<Cow<'a, str>>,
+
+    /// The application component, e.g. `BC-DWB`
+    #[builder(setter(into), default)]
+    application_component: Option<Cow<'a, str>>,
+}
+
+impl<'a> ValidatePackageBuilder<'a> {
+    /// Takes over the properties of a package that is to be created.
+    pub fn package(&mut self, package: &'a NewPackage<'a>) -> &mut Self {
+        self.name = Some(package.name().into());
+        self.description = Some(package.description().into());
+        self.package_type = Some(package.package_type().clone());
+        self.super_package = Some(package.super_package().map(Cow::Borrowed));
+        self.software_component = Some(package.software_component().map(Cow::Borrowed));
+        self.transport_layer = Some(package.transport_layer().map(Cow::Borrowed));
+        self.application_component = Some(package.application_component().map(Cow::Borrowed));
+        self
+    }
+}
+
+impl<'a> From<&'a NewPackage<'a>> for ValidatePackage<'a> {
+    fn from(package: &'a NewPackage<'a>) -> Self {
+        Self {
+            name: package.name().into(),
+            description: package.description().into(),
+            super_package: package.super_package().map(Cow::Borrowed),
+            package_type: package.package_type().clone(),
+            software_component: package.software_component().map(Cow::Borrowed),
+            transport_layer: package.transport_layer().map(Cow::Borrowed),
+            application_component: package.application_component().map(Cow::Borrowed),
+        }
+    }
+}
+
+impl Operation for ValidatePackage<'_> {
+    type Response = Success<AsxData<ValidationResult>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "packages/validation".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("objname", &self.name);
+        params.push("description", &self.description);
+        params.push_opt("packagename", self.super_package.as_ref());
+        params.push("packagetype", self.package_type.clone());
+        params.push_opt("swcomp", self.software_component.as_ref());
+        params.push_opt("transportLayer", self.transport_layer.as_ref());
+        params.push_opt("appcomponent", self.application_component.as_ref());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.as+xml; charset=utf-8; dataname=com.sap.adt.validationMessages",
+            ),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a new package, packages that are not local require a transport.
+///
+/// Does not validate the package, see [`create_package`] to validate it first.
+///
+/// Operation `/sap/bc/adt/packages`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreatePackage<'a> {
+    /// The package to create, see [`package::NewPackageBuilder`]
+    package: NewPackage<'a>,
+
+    /// The transport to record the creation in, e.g. `A4HK900089`
+    #[builder(setter(into), default)]
+    transport: Option<Cow<'a, str>>,
+}
+
+impl Operation for CreatePackage<'_> {
+    type Response = Success<package::Package>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "packages".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push_opt("corrNr", self.transport.as_ref());
+        params
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.package.into_xml_root())
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/vnd.sap.adt.packages.v1+xml"),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.packages.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Validates the package through [`ValidatePackage`] and only creates it through
+/// [`CreatePackage`] if the validation passed.
+///
+/// Fails with [`ValidationFailed`] without creating the package otherwise.
+pub async fn create_package<T>(
+    client: &Client<T>,
+    package: NewPackage<'_>,
+    transport: Option<&str>,
+) -> Result<(), OperationError>
+where
+    T: RequestDispatch,
+{
+    let validation = ValidatePackage::from(&package).dispatch(client).await?;
+    if !validation.body().is_ok() {
+        return Err(ValidationFailed {
+            name: package.name().to_owned(),
+            severity: validation.body().severity.clone(),
+            message: validation.body().short_text.clone(),
+        }
+        .into());
+    }
+
+    CreatePackage {
+        package,
+        transport: transport.map(Cow::Borrowed),
+    }
+    .dispatch(client)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::package::NewPackageBuilder, testing::FakeSystem};
+    use http::Method;
+
+    #[test]
+    fn namespaced_package_names_are_encoded() {
+        let op = PackageBuilder::default().name("/ABC/PKG").build().unwrap();
+        assert_eq!(op.url(), "packages/%2Fabc%2Fpkg");
+
+        let op = PackageBuilder::default().name("$TMP").build().unwrap();
+        assert_eq!(op.url(), "packages/%24tmp");
+    }
+
+    #[test]
+    fn validation_takes_over_new_package() {
+        let package = NewPackageBuilder::default()
+            .name("$MY_PACKAGE")
+            .description("My Package")
+            .super_package("$TMP")
+            .software_component("LOCAL")
+            .build()
+            .unwrap();
+
+        let op = ValidatePackageBuilder::default()
+            .package(&package)
+            .build()
+            .unwrap();
+
+        assert_eq!(op.name, "$MY_PACKAGE");
+        assert_eq!(op.super_package.as_deref(), Some("$TMP"));
+        assert!(op.transport_layer.is_none());
+    }
+
+    #[tokio::test]
+    async fn package_is_not_created_if_validation_fails() {
+        let system = FakeSystem::responding(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <SEVERITY>ERROR</SEVERITY>
+                        <SHORT_TEXT>Package $MY_PACKAGE already exists</SHORT_TEXT>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#,
+        );
+
+        let package = NewPackageBuilder::default()
+            .name("$MY_PACKAGE")
+            .description("My Package")
+            .super_package("$TMP")
+            .software_component("LOCAL")
+            .build()
+            .unwrap();
+
+        let result = create_package(&system.client(), package, None).await;
+        let Err(OperationError::ValidationFailed(err)) = result else {
+            panic!("Expected the validation to fail.");
+        };
+        assert_eq!(err.name, "$MY_PACKAGE");
+
+        assert!(system.requested(Method::POST, "/packages/validation"));
+        assert!(!system.requested(Method::POST, "/packages"));
+    }
+
+    #[test]
+    fn package_is_mandatory_for_creation() {
+        let result = CreatePackageBuilder::default()
+            .transport("A4HK900089")
+            .build();
+
+        assert!(result.is_err(), "Package should not be optional");
+    }
+}
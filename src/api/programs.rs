@@ -7,6 +7,7 @@ use crate::operation::{Operation, Stateless};
 use crate::response::{CacheControlled, Plain, Success};
 use crate::{
     QueryParameters,
+    api::headers::cache_headers,
     models::{
         abapsource::ObjectStructureElement, adtcore, atom::VersionFeed, program::AbapProgram,
     },
@@ -47,18 +48,11 @@ impl Operation for Program<'_> {
         params
     }
 
-    /// Headers need to handle whether we have a cached version locally and provide the ETag.
     fn headers(&self) -> Option<http::HeaderMap> {
-        let mut map = HeaderMap::new();
-        match &self.etag {
-            None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
-            Some(etag) => map.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap()),
-        };
-        map.insert(
-            header::ACCEPT,
-            HeaderValue::from_static("application/vnd.sap.adt.programs.programs.v3+xml"),
-        );
-        Some(map)
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.programs.programs.v3+xml",
+        ))
     }
 }
 
@@ -94,15 +88,8 @@ impl<'a> Operation for ProgramSource<'a> {
         params
     }
 
-    /// Headers need to handle whether we have a cached version locally and provide the ETag.
     fn headers(&self) -> Option<http::HeaderMap> {
-        let mut map = HeaderMap::new();
-        match &self.etag {
-            None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
-            Some(etag) => map.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap()),
-        };
-        map.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
-        Some(map)
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
     }
 }
 
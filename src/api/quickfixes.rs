@@ -0,0 +1,103 @@
+/// Operations to list the quick fixes at a position of the source code and to apply them.
+///
+/// Messages of check runs indicate available fixes, see
+/// [`crate::models::checkrun::Message::has_quick_fix`]
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::{
+    QueryParameters,
+    models::{
+        abapsource::SourceLocation,
+        quickfixes::{Proposal, ProposalRequest, ProposalResult, Proposals},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+fn quickfix_headers() -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/*"),
+    );
+    headers.insert(header::ACCEPT, HeaderValue::from_static("application/*"));
+    Some(headers)
+}
+
+/// Lists the quick fixes available at a position of the source code.
+///
+/// Operation `/sap/bc/adt/quickfixes/evaluation?uri={uri}#start={line},{column}`
+#[derive(Builder, Debug)]
+pub struct QuickFixProposals<'a> {
+    /// The source and the position to evaluate, e.g. `…/zwegwerf1/source/main#start=5,4`
+    location: SourceLocation,
+
+    /// The current source code, fixes are evaluated against it rather than the saved one.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for QuickFixProposals<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<Proposals>;
+
+    fn url(&self) -> Cow<'static, str> {
+        "quickfixes/evaluation".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", self.location.to_string());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        quickfix_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Applies a proposal of [`QuickFixProposals`], the result holds the text deltas to change
+/// the source code with - the source code itself is not changed.
+///
+/// Operation `{proposal uri}`, e.g. `/sap/bc/adt/quickfixes/abap/declare_local_variable`
+#[derive(Builder, Debug)]
+pub struct ApplyQuickFix<'a> {
+    /// The proposal to apply.
+    proposal: &'a Proposal,
+
+    /// The position the proposal was evaluated at.
+    location: SourceLocation,
+
+    /// The source code the proposal was evaluated against.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl Operation for ApplyQuickFix<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ProposalResult>;
+
+    fn url(&self) -> Cow<'static, str> {
+        self.proposal.uri().to_owned().into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        quickfix_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let location = self.location.to_string();
+        Some(ProposalRequest::new(self.proposal, &location, &self.source).into_xml_root())
+    }
+}
@@ -0,0 +1,212 @@
+/// Operations to refactor source code and objects, e.g. to rename a variable or to move
+/// objects to another package.
+///
+/// Every refactoring is evaluated first, then previewed with the input of the user and
+/// finally executed, each step returning the input of the next one:
+/// [`Evaluate`] → [`Preview`] → [`Execute`]
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::{borrow::Cow, marker::PhantomData};
+
+use crate::{
+    QueryParameters,
+    models::{
+        abapsource::SourceLocation,
+        refactoring::{ChangePackage, ChangeSet, Refactoring},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+/// A step of the refactoring protocol.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    Evaluate,
+    Preview,
+    Execute,
+}
+
+impl Step {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Evaluate => "evaluate",
+            Self::Preview => "preview",
+            Self::Execute => "execute",
+        }
+    }
+}
+
+fn step_parameters<'a, R: Refactoring>(step: Step) -> QueryParameters<'a> {
+    let mut params = QueryParameters::default();
+    params.push("step", step.as_str());
+    params.push("rel", R::RELATION);
+    params
+}
+
+fn refactoring_headers() -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/*"),
+    );
+    headers.insert(header::ACCEPT, HeaderValue::from_static("application/*"));
+    Some(headers)
+}
+
+/// Evaluates a refactoring at a position, e.g. a rename of the identifier at the position
+/// or the extraction of the statements within the range into a method.
+///
+/// ## Example:
+/// ```
+/// use adt_query::{
+///     api::refactoring::EvaluateBuilder,
+///     models::{abapsource::{Position, SourceLocation}, refactoring::Rename},
+/// };
+///
+/// EvaluateBuilder::<Rename>::default()
+///     .location(SourceLocation::new(
+///         "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+///         Position::new(2, 5),
+///         None,
+///     ))
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Operation `/sap/bc/adt/refactorings?step=evaluate&rel={relation}`
+#[derive(Builder, Debug)]
+pub struct Evaluate<R: Refactoring> {
+    /// The position or range to refactor, e.g. `…/zwegwerf1/source/main#start=2,5`
+    location: SourceLocation,
+
+    #[builder(setter(skip))]
+    refactoring: PhantomData<R>,
+}
+
+impl<R: Refactoring> Operation for Evaluate<R> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<R>;
+
+    fn url(&self) -> Cow<'static, str> {
+        R::ENDPOINT.into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = step_parameters::<R>(Step::Evaluate);
+        params.push("uri", self.location.to_string());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        refactoring_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(ChangeSet::evaluation(self.location.to_string()).into_xml_root())
+    }
+}
+
+/// Evaluates moving an object to another package.
+///
+/// Operation `/sap/bc/adt/refactoring/changepackage?step=evaluate`
+#[derive(Builder, Debug)]
+pub struct EvaluateChangePackage<'a> {
+    /// The URI of the object to move, e.g. `/sap/bc/adt/oo/classes/zcl_demo`
+    #[builder(setter(into))]
+    object_uri: Cow<'a, str>,
+}
+
+impl Operation for EvaluateChangePackage<'_> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<ChangePackage>;
+
+    fn url(&self) -> Cow<'static, str> {
+        ChangePackage::ENDPOINT.into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = step_parameters::<ChangePackage>(Step::Evaluate);
+        params.push("uri", &self.object_uri);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        refactoring_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(ChangeSet::evaluation(self.object_uri.as_ref()).into_xml_root())
+    }
+}
+
+/// Determines the changes of an evaluated refactoring, after the input of the user was
+/// provided, e.g. through [`crate::models::refactoring::Rename::set_new_name`]
+///
+/// Operation `/sap/bc/adt/refactorings?step=preview&rel={relation}`
+#[derive(Builder, Debug)]
+pub struct Preview<'a, R: Refactoring> {
+    /// The result of [`Evaluate`] with the input of the user.
+    refactoring: &'a R,
+}
+
+impl<R: Refactoring> Operation for Preview<'_, R> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<R>;
+
+    fn url(&self) -> Cow<'static, str> {
+        R::ENDPOINT.into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        step_parameters::<R>(Step::Preview)
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        refactoring_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.refactoring.into_xml_root())
+    }
+}
+
+/// Applies the changes of a previewed refactoring.
+///
+/// The transport is taken from the change set, see [`ChangeSet::set_transport`]
+///
+/// Operation `/sap/bc/adt/refactorings?step=execute&rel={relation}`
+#[derive(Builder, Debug)]
+pub struct Execute<'a, R: Refactoring> {
+    /// The result of [`Preview`]
+    refactoring: &'a R,
+}
+
+impl<R: Refactoring> Operation for Execute<'_, R> {
+    const METHOD: http::Method = http::Method::POST;
+
+    type Kind = Stateless;
+    type Response = Success<R>;
+
+    fn url(&self) -> Cow<'static, str> {
+        R::ENDPOINT.into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        step_parameters::<R>(Step::Execute)
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        refactoring_headers()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.refactoring.into_xml_root())
+    }
+}
@@ -6,10 +6,16 @@ use http::{HeaderValue, header};
 use crate::{
     QueryParameters,
     models::{
+        adtcore::ObjectReferences,
         facets::Facets,
+        nameditem::NamedItemList,
         objectproperties,
         serialize::IntoXmlRoot,
         tpr,
+        usagereferences::{
+            SnippetObject, UsageReferenceRequest, UsageReferenceResult, UsageSnippetRequest,
+            UsageSnippetResult,
+        },
         vfs::{Facet, FacetOrder, Preselection, VirtualFoldersRequest, VirtualFoldersResult},
     },
     operation::{Operation, Stateless},
@@ -116,6 +122,74 @@ impl<'a> Operation for RepositoryContent<'a> {
     }
 }
 
+/// Searches the repository for objects by name, the way the "Open ABAP Development Object"
+/// dialog does. Much cheaper than a [`RepositoryContent`] query when the name is known.
+///
+/// Operation `/sap/bc/adt/repository/informationsystem/search?operation=quickSearch`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct QuickSearch<'a> {
+    /// The pattern that the object names must match, e.g. `ZCL_*` or `zwegwerf1`
+    #[builder(setter(into))]
+    query: Cow<'a, str>,
+
+    /// Restricts the result to the given object types, e.g. `PROG/P` or `CLAS/OC`.
+    #[builder(setter(each(name = "object_type", into)), default)]
+    object_types: Vec<Cow<'a, str>>,
+
+    /// Restricts the result to objects in the given packages.
+    #[builder(setter(each(name = "package", into)), default)]
+    packages: Vec<Cow<'a, str>>,
+
+    /// The maximum number of objects to return.
+    ///
+    /// When unspecified in the query, the server decides on the limit.
+    #[builder(default)]
+    max_results: Option<u64>,
+
+    /// Whether the descriptions of the objects should be left out of the result.
+    ///
+    /// When unspecified in the query, the default behavior is `False`.
+    #[builder(default)]
+    no_description: Option<bool>,
+}
+
+impl Operation for QuickSearch<'_> {
+    type Kind = Stateless;
+
+    type Response = Success<ObjectReferences>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/search".into()
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/xml"),
+        );
+        Some(headers)
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("operation", "quickSearch");
+        params.push("query", &self.query);
+        params.push_opt("maxResults", self.max_results);
+        params.push_opt("noDescription", self.no_description);
+        self.object_types.iter().for_each(|kind| {
+            params.push("objectType", kind);
+        });
+        self.packages.iter().for_each(|package| {
+            params.push("packageName", package);
+        });
+        params
+    }
+}
+
 /// Fetches the available facets from the server.
 ///
 /// Responsible ABAP REST Handler: `CL_RIS_ADT_RES_VIRTUAL_FOLDERS`
@@ -134,6 +208,52 @@ impl Operation for AvailableFacets {
     }
 }
 
+/// Fetches the possible values of a facet, e.g. all owners or application components.
+///
+/// Used to offer value help when building a [`Preselection`]. The facets that support this
+/// advertise a template link to this Operation in their [`crate::models::facets::Facet`].
+///
+/// Operation `/sap/bc/adt/repository/informationsystem/properties/values`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PropertyValues<'a> {
+    /// The facet to get the values of, e.g. [`Facet::Owner`] or [`Facet::CreationDate`]
+    facet: Facet,
+
+    /// Optional: a pattern the values must match, e.g. `DEV*`
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl Operation for PropertyValues<'_> {
+    type Kind = Stateless;
+
+    type Response = Success<NamedItemList>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/properties/values".into()
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.nameditems.v1+xml"),
+        );
+        Some(headers)
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        // The server advertises the facet keys in lowercase, e.g. `data=owner`
+        params.push("data", self.facet.as_str().to_lowercase());
+        params.push_opt("name", self.name.as_ref());
+        params
+    }
+}
+
 /// Fetches the properties of an object in the ABAP Workbench.
 ///
 /// This Operation is typically used to display information about an object
@@ -229,3 +349,108 @@ impl Operation for ObjectTransports<'_> {
         params
     }
 }
+
+/// Fetches the where-used list of an object, i.e. all objects referencing it.
+///
+/// Operation `/sap/bc/adt/repository/informationsystem/usageReferences`
+#[derive(Debug, Builder)]
+pub struct UsageReferences<'a> {
+    /// The URI of the object to get the usages of, mandatory parameter.
+    ///
+    /// For example, `/sap/bc/adt/oo/classes/zcl_foo`. To get the usages of a component,
+    /// such as a method, append its position: `/sap/bc/adt/oo/classes/zcl_foo/source/main#start=12,10`
+    #[builder(setter(into))]
+    object_uri: Cow<'a, str>,
+
+    /// Restricts the result to referencing objects of the given types, e.g. `PROG/P`.
+    #[builder(setter(each(name = "object_type", into)), default)]
+    object_types: Vec<Cow<'a, str>>,
+
+    /// Restricts the result to referencing objects in the given packages.
+    #[builder(setter(each(name = "package", into)), default)]
+    packages: Vec<Cow<'a, str>>,
+}
+
+impl Operation for UsageReferences<'_> {
+    type Kind = Stateless;
+
+    type Response = Success<UsageReferenceResult>;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/usageReferences".into()
+    }
+
+    fn parameters(&self) -> QueryParameters<'_> {
+        let mut params = QueryParameters::default();
+        params.push("uri", &self.object_uri);
+        params
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UsageReferenceRequest::new(&self.object_types, &self.packages).into_xml_root())
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagereferences.request.v1+xml",
+            ),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagereferences.result.v1+xml",
+            ),
+        );
+        Some(headers)
+    }
+}
+
+/// Fetches the code snippets in which the references of a where-used list occur.
+///
+/// The referencing objects are taken from a prior [`UsageReferences`] result.
+///
+/// Operation `/sap/bc/adt/repository/informationsystem/usageSnippets`
+#[derive(Debug, Builder)]
+pub struct UsageSnippets<'a> {
+    /// The referencing objects to get the snippets of.
+    #[builder(setter(each(name = "object")))]
+    objects: Vec<SnippetObject<'a>>,
+}
+
+impl Operation for UsageSnippets<'_> {
+    type Kind = Stateless;
+
+    type Response = Success<UsageSnippetResult>;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/usageSnippets".into()
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UsageSnippetRequest::new(&self.objects).into_xml_root())
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagesnippets.request.v1+xml",
+            ),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagesnippets.result.v1+xml",
+            ),
+        );
+        Some(headers)
+    }
+}
@@ -7,6 +7,8 @@ pub enum ResponseError {
     BadStatusCode(http::Response<String>),
     #[error(transparent)]
     DeserializeError(#[from] serde_xml_rs::Error),
+    #[error("invalid response body: {0}")]
+    InvalidBody(String),
 }
 
 #[derive(Debug, Error)]
@@ -21,6 +23,69 @@ pub enum RequestError {
     InvalidUrl(#[from] url::ParseError),
 }
 
+/// A URI did not contain a valid source position fragment, e.g. `#start=12,4;end=12,10`
+#[derive(Debug, Error)]
+#[error("not a valid source location: '{0}'")]
+pub struct InvalidSourceLocation(pub String);
+
+/// The source code of a function module does not start with a valid `FUNCTION` statement.
+#[derive(Debug, Error)]
+#[error("not a valid function module signature: {0}")]
+pub struct InvalidFunctionSignature(pub String);
+
+/// An object is recorded in transport requests but no usable request was provided for the change.
+#[derive(Debug, Error)]
+#[error("a transport request is required to change '{uri}'")]
+pub struct TransportRequired {
+    /// The URI of the object that was to be changed.
+    pub uri: String,
+
+    /// The numbers of the modifiable requests the change could be recorded in, the given
+    /// request was none of these.
+    pub candidates: Vec<String>,
+}
+
+/// A transport request was provided for a change, but the object is locked in another one.
+#[derive(Debug, Error)]
+#[error("'{uri}' is locked in transport request {locked_in}, not in {requested}")]
+pub struct TransportMismatch {
+    /// The URI of the object that was to be changed.
+    pub uri: String,
+
+    /// The number of the request that was provided for the change.
+    pub requested: String,
+
+    /// The number of the request the object is locked in.
+    pub locked_in: String,
+}
+
+/// The server rejected the name or properties of an object that was to be created.
+#[derive(Debug, Error)]
+#[error("validation of '{name}' failed: {}", .message.as_deref().unwrap_or("no details provided"))]
+pub struct ValidationFailed {
+    /// The name of the object that was to be created.
+    pub name: String,
+
+    /// The severity of the finding, e.g. `ERROR`
+    pub severity: Option<String>,
+
+    /// The short text of the finding.
+    pub message: Option<String>,
+}
+
+/// A URI Template could not be parsed, see [`crate::uritemplate::UriTemplate`]
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("expression opened at {0} is never closed")]
+    UnclosedExpression(usize),
+
+    #[error("unexpected '}}' at {0} outside of an expression")]
+    UnexpectedClose(usize),
+
+    #[error("invalid expression: '{{{0}}}'")]
+    InvalidExpression(String),
+}
+
 /// Something went wrong with dispatching the request to the backend.
 #[derive(Error, Debug)]
 pub enum DispatchError {
@@ -54,4 +119,16 @@ pub enum OperationError {
 
     #[error("value for field '{0}' was not provided")]
     UninitializedField(&'static str),
+
+    #[error("invalid value: {0}")]
+    InvalidField(String),
+
+    #[error(transparent)]
+    TransportRequired(#[from] TransportRequired),
+
+    #[error(transparent)]
+    TransportMismatch(#[from] TransportMismatch),
+
+    #[error(transparent)]
+    ValidationFailed(#[from] ValidationFailed),
 }
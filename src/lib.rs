@@ -9,6 +9,10 @@ mod client;
 mod core;
 
 pub mod session;
+pub mod uritemplate;
+
+#[cfg(test)]
+mod testing;
 pub use core::*;
 
 pub mod api;
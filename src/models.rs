@@ -1,14 +1,34 @@
 pub mod abapsource;
+pub mod abapunit;
+pub mod activation;
 pub mod adtcomp;
 pub mod adtcore;
 pub mod asx;
+pub mod atc;
 pub mod atom;
+pub mod cds;
 pub mod checkrun;
+pub mod class;
+pub mod codecompletion;
+pub mod creation;
+pub mod cts;
+pub mod datapreview;
+pub mod deletion;
 pub mod discovery;
 pub mod facets;
+pub mod function;
+pub mod hierarchy;
+pub mod include;
+pub mod interface;
+pub mod nameditem;
 pub mod objectproperties;
+pub mod occurrencemarkers;
+pub mod package;
 pub mod program;
+pub mod quickfixes;
+pub mod refactoring;
 pub mod tpr;
+pub mod usagereferences;
 pub mod vfs;
 
 pub(crate) mod serialize;
@@ -1,5 +1,9 @@
-use crate::models::atom;
-use serde::Deserialize;
+use crate::{
+    error::InvalidSourceLocation,
+    models::{atom, serialize::IntoXmlRoot},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{borrow::Cow, fmt, str::FromStr};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "abapsource:syntaxConfiguration")]
@@ -39,11 +43,200 @@ pub struct ObjectStructureElement {
     pub elements: Vec<Self>,
 }
 
+/// A position in the source code of an object.
+///
+/// As in the ADT URI fragments, lines start at `1` whereas columns start at `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Position {
+    pub fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A location in the source code of an object, expressed by the server as URI fragment.
+///
+/// For example, `/sap/bc/adt/oo/classes/z_syntax_test/source/main#start=193,19;end=193,40`
+/// refers to line 193 from column 19 to 40 in the main source of `Z_SYNTAX_TEST`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLocation {
+    /// The URI of the source without the fragment, e.g. `/sap/bc/adt/programs/programs/zwegwerf1/source/main`
+    pub uri: String,
+
+    /// The position the location starts at.
+    pub start: Position,
+
+    /// Optional: the position the location ends at, not all locations are ranges.
+    pub end: Option<Position>,
+}
+
+impl SourceLocation {
+    pub fn new<T: Into<String>>(uri: T, start: Position, end: Option<Position>) -> Self {
+        Self {
+            uri: uri.into(),
+            start,
+            end,
+        }
+    }
+}
+
+impl FromStr for SourceLocation {
+    type Err = InvalidSourceLocation;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidSourceLocation(value.to_owned());
+        let parse_position = |position: &str| {
+            let (line, column) = position.split_once(',').ok_or_else(invalid)?;
+            Ok::<_, InvalidSourceLocation>(Position::new(
+                line.parse().map_err(|_| invalid())?,
+                column.parse().map_err(|_| invalid())?,
+            ))
+        };
+
+        let (uri, fragment) = value.split_once('#').ok_or_else(invalid)?;
+        let (mut start, mut end) = (None, None);
+        for part in fragment.split(';') {
+            match part.split_once('=') {
+                Some(("start", position)) => start = Some(parse_position(position)?),
+                Some(("end", position)) => end = Some(parse_position(position)?),
+                _ => {}
+            }
+        }
+
+        Ok(Self::new(uri, start.ok_or_else(invalid)?, end))
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}#start={},{}",
+            self.uri, self.start.line, self.start.column
+        )?;
+        if let Some(end) = self.end {
+            write!(f, ";end={},{}", end.line, end.column)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for SourceLocation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The severity of a message the server reports about source code, e.g. during activation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// A severity unknown to this crate with the letter reported by the server, ranks below
+    /// all known severities.
+    Unknown(String),
+    /// `I` - purely informational
+    Info,
+    /// `S` - the action was successful
+    Success,
+    /// `W` - should be looked at but does not prevent the action
+    Warning,
+    /// `E` - prevents the action, e.g. a syntax error
+    Error,
+    /// `A` - the action was aborted
+    Abort,
+}
+
+impl Severity {
+    /// Whether the message prevents the action it was reported for.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error | Self::Abort)
+    }
+}
+
+// Deserialized from the plain value, as the severity is provided as attribute or element text.
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match String::deserialize(deserializer)?.as_str() {
+            "I" => Ok(Self::Info),
+            "S" => Ok(Self::Success),
+            "W" => Ok(Self::Warning),
+            "E" => Ok(Self::Error),
+            "A" | "X" => Ok(Self::Abort),
+            other => Ok(Self::Unknown(other.to_owned())),
+        }
+    }
+}
+
+/// How the pretty printer converts the case of keywords and identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum KeywordCase {
+    /// Keywords are upper case, identifiers lower case.
+    #[serde(rename = "keywordUpper")]
+    KeywordUpper,
+    /// Keywords are lower case, identifiers upper case.
+    #[serde(rename = "keywordLower")]
+    KeywordLower,
+    /// Keywords are upper case, identifiers keep their case.
+    #[serde(rename = "keywordAuto")]
+    KeywordAuto,
+    /// Keywords and identifiers keep their case.
+    #[serde(rename = "none")]
+    Unchanged,
+}
+
+/// The settings of the pretty printer of the logged on user.
+///
+/// Read through the settings operation, modified and sent back to update them.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "abapformatter:PrettyPrinterSettings")]
+pub struct PrettyPrinterSettings {
+    /// Whether statements are indented according to their nesting.
+    #[serde(rename = "@abapformatter:indentation")]
+    pub indentation: bool,
+
+    /// How the case of keywords and identifiers is converted.
+    #[serde(rename = "@abapformatter:style")]
+    pub style: KeywordCase,
+}
+
+impl IntoXmlRoot for PrettyPrinterSettings {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![(
+            "abapformatter".into(),
+            "http://www.sap.com/adt/prettyprintersettings".into(),
+        )]
+    }
+}
+
 #[cfg(test)]
 
 mod tests {
     use super::*;
 
+    #[test]
+    fn unknown_severity_does_not_fail() {
+        #[derive(Deserialize)]
+        struct Message {
+            #[serde(rename = "@severity")]
+            severity: Severity,
+        }
+
+        let message: Message = serde_xml_rs::from_str(r#"<message severity="Q"/>"#).unwrap();
+        assert_eq!(message.severity, Severity::Unknown("Q".into()));
+        assert!(!message.severity.is_error());
+        assert!(message.severity < Severity::Info);
+    }
+
     #[test]
     fn deserialize_program_object_structure() {
         let plain = r#"<abapsource:objectStructureElement xml:base="/sap/bc/adt/programs/programs/z_badi_check/source/main" adtcore:name="Z_BADI_CHECK" adtcore:type="PROG/P" xmlns:adtcore="http://www.sap.com/adt/core" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:atom="http://www.w3.org/2005/Atom">
@@ -73,4 +266,62 @@ mod tests {
         let result: ObjectStructureElement = serde_xml_rs::from_str(plain).unwrap();
         println!("{:?}", result);
     }
+
+    #[test]
+    fn parse_source_location_range() {
+        let location: SourceLocation =
+            "/sap/bc/adt/oo/classes/z_syntax_test/source/main#start=193,19;end=193,40"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            location.uri,
+            "/sap/bc/adt/oo/classes/z_syntax_test/source/main"
+        );
+        assert_eq!(location.start, Position::new(193, 19));
+        assert_eq!(location.end, Some(Position::new(193, 40)));
+        assert_eq!(
+            location.to_string(),
+            "/sap/bc/adt/oo/classes/z_syntax_test/source/main#start=193,19;end=193,40"
+        );
+    }
+
+    #[test]
+    fn parse_source_location_without_end() {
+        let location: SourceLocation =
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=5,0"
+                .parse()
+                .unwrap();
+
+        assert_eq!(location.start, Position::new(5, 0));
+        assert_eq!(location.end, None);
+    }
+
+    #[test]
+    fn source_location_requires_start() {
+        let result =
+            "/sap/bc/adt/programs/programs/z_badi_check/source/main#type=PROG%2FPD;name=GC_ACTTYPE"
+                .parse::<SourceLocation>();
+        assert!(result.is_err());
+        assert!(
+            "/sap/bc/adt/programs/programs/zwegwerf1"
+                .parse::<SourceLocation>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn pretty_printer_settings_round_trip() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?><abapformatter:PrettyPrinterSettings xmlns:abapformatter="http://www.sap.com/adt/prettyprintersettings" abapformatter:indentation="true" abapformatter:style="keywordUpper"/>"#;
+
+        let mut settings: PrettyPrinterSettings = serde_xml_rs::from_str(plain).unwrap();
+        assert!(settings.indentation);
+        assert_eq!(settings.style, KeywordCase::KeywordUpper);
+
+        settings.style = KeywordCase::Unchanged;
+        assert_eq!(
+            settings.into_xml_root().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><abapformatter:PrettyPrinterSettings xmlns:abapformatter="http://www.sap.com/adt/prettyprintersettings" abapformatter:indentation="true" abapformatter:style="none" />"#
+        );
+    }
 }
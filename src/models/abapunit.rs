@@ -0,0 +1,824 @@
+/// ABAP Unit - http://www.sap.com/adt/aunit
+///
+/// Covers the configuration of a test run, its results and the coverage measured during
+/// the run (http://www.sap.com/adt/cov).
+use crate::{
+    models::{
+        adtcore::{ObjectSet, UriReferences},
+        serialize::IntoXmlRoot,
+    },
+    uritemplate::encode_segment,
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The risk level of a test class, tests can be restricted to a maximum risk level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    /// The test does not change any persistent data
+    Harmless,
+    /// The test may change persistent data
+    Dangerous,
+    /// The test may change system settings or customizing
+    Critical,
+}
+
+/// The expected duration of a test class, tests can be restricted to a maximum duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestDuration {
+    Short,
+    Medium,
+    Long,
+}
+
+/// Configuration of a test run, i.e. what to test and which tests to include.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::abapunit::{RiskLevel, RunConfigurationBuilder};
+///
+/// RunConfigurationBuilder::default()
+///     .object("/sap/bc/adt/oo/classes/zcl_demo")
+///     .package("$TMP")
+///     .max_risk_level(RiskLevel::Dangerous)
+///     .coverage(true)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct RunConfiguration {
+    /// The URIs of the objects and packages to test.
+    #[builder(setter(custom))]
+    scope: Vec<String>,
+
+    /// Tests with a higher risk level are skipped.
+    #[builder(default = RiskLevel::Critical)]
+    max_risk_level: RiskLevel,
+
+    /// Tests with a longer duration are skipped.
+    #[builder(default = TestDuration::Long)]
+    max_duration: TestDuration,
+
+    /// Whether the coverage should be measured, see [`CoverageRef`]
+    #[builder(default)]
+    coverage: bool,
+}
+
+impl RunConfigurationBuilder {
+    /// Adds the tests of an object, e.g. `/sap/bc/adt/oo/classes/zcl_demo`
+    pub fn object<T: Into<String>>(&mut self, uri: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(uri.into());
+        self
+    }
+
+    /// Adds the tests of all objects in a package, e.g. `$TMP` or `/ABC/PKG`
+    pub fn package(&mut self, name: &str) -> &mut Self {
+        self.object(format!(
+            "/sap/bc/adt/packages/{}",
+            encode_segment(&name.to_lowercase())
+        ))
+    }
+}
+
+impl RunConfiguration {
+    pub fn scope(&self) -> &[String] {
+        &self.scope
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Active {
+    #[serde(rename = "@active")]
+    active: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct External {
+    coverage: Active,
+}
+
+#[derive(Debug, Serialize)]
+struct Value {
+    #[serde(rename = "@value")]
+    value: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Enabled {
+    #[serde(rename = "@enabled")]
+    enabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct DeterminationStrategy {
+    #[serde(rename = "@sameProgram")]
+    same_program: bool,
+
+    #[serde(rename = "@assignedTests")]
+    assigned_tests: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RiskLevels {
+    #[serde(rename = "@harmless")]
+    harmless: bool,
+
+    #[serde(rename = "@dangerous")]
+    dangerous: bool,
+
+    #[serde(rename = "@critical")]
+    critical: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct Durations {
+    #[serde(rename = "@short")]
+    short: bool,
+
+    #[serde(rename = "@medium")]
+    medium: bool,
+
+    #[serde(rename = "@long")]
+    long: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct Options {
+    #[serde(rename = "uriType")]
+    uri_type: Value,
+
+    #[serde(rename = "testDeterminationStrategy")]
+    determination_strategy: DeterminationStrategy,
+
+    #[serde(rename = "testRiskLevels")]
+    risk_levels: RiskLevels,
+
+    #[serde(rename = "testDurations")]
+    durations: Durations,
+
+    #[serde(rename = "withNavigationUri")]
+    navigation_uri: Enabled,
+}
+
+#[derive(Debug, Serialize)]
+struct ObjectSets<'a> {
+    #[serde(rename = "objectSet")]
+    set: ObjectSet<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "aunit:runConfiguration")]
+struct RunConfigurationBody<'a> {
+    external: External,
+    options: Options,
+
+    #[serde(rename = "adtcore:objectSets")]
+    object_sets: ObjectSets<'a>,
+}
+
+impl Serialize for RunConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RunConfigurationBody {
+            external: External {
+                coverage: Active {
+                    active: self.coverage,
+                },
+            },
+            options: Options {
+                uri_type: Value { value: "semantic" },
+                determination_strategy: DeterminationStrategy {
+                    same_program: true,
+                    assigned_tests: false,
+                },
+                risk_levels: RiskLevels {
+                    harmless: true,
+                    dangerous: self.max_risk_level >= RiskLevel::Dangerous,
+                    critical: self.max_risk_level >= RiskLevel::Critical,
+                },
+                durations: Durations {
+                    short: true,
+                    medium: self.max_duration >= TestDuration::Medium,
+                    long: self.max_duration >= TestDuration::Long,
+                },
+                navigation_uri: Enabled { enabled: true },
+            },
+            object_sets: ObjectSets {
+                set: ObjectSet::inclusive(&self.scope),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for RunConfiguration {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("aunit".into(), "http://www.sap.com/adt/aunit".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+/// The kind of an [`Alert`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertKind {
+    /// An assertion of the test failed, e.g. `cl_abap_unit_assert=>assert_equals`
+    FailedAssertion,
+    /// An exception was raised and not caught by the test
+    Exception,
+    /// A warning, e.g. about the configuration of the test class
+    Warning,
+    #[serde(other)]
+    Other,
+}
+
+/// The severity of an [`Alert`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSeverity {
+    Tolerable,
+    Critical,
+    Fatal,
+    #[serde(other)]
+    Other,
+}
+
+/// A detail of an [`Alert`], e.g. the expected and actual value of an assertion.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "detail")]
+#[readonly::make]
+pub struct AlertDetail {
+    #[serde(rename = "@text")]
+    pub text: String,
+
+    /// Nested details, e.g. the differences of two tables
+    #[serde(rename = "details", default)]
+    pub details: AlertDetails,
+}
+
+/// Wraps a collection of [`AlertDetail`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "details")]
+#[readonly::make]
+pub struct AlertDetails {
+    #[serde(rename = "detail", default)]
+    pub details: Vec<AlertDetail>,
+}
+
+/// An entry of the call stack of an [`Alert`], i.e. where the alert was raised.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "stackEntry")]
+#[readonly::make]
+pub struct StackEntry {
+    /// The URI of the source position, e.g. `/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#start=21,0`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// A description of the position, e.g. `Include: <ZCL_DEMO======CCAU> Line: <21>`
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+}
+
+/// Wraps a collection of [`StackEntry`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "stack")]
+#[readonly::make]
+pub struct Stack {
+    #[serde(rename = "stackEntry", default)]
+    pub entries: Vec<StackEntry>,
+}
+
+/// Something that went wrong during the test, e.g. a failed assertion.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "alert")]
+#[readonly::make]
+pub struct Alert {
+    #[serde(rename = "@kind")]
+    pub kind: AlertKind,
+
+    #[serde(rename = "@severity")]
+    pub severity: AlertSeverity,
+
+    /// The title of the alert, e.g. `Critical Assertion Error: 'Equals'`
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "details", default)]
+    pub details: AlertDetails,
+
+    #[serde(rename = "stack", default)]
+    pub stack: Stack,
+}
+
+/// Wraps a collection of [`Alert`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "alerts")]
+#[readonly::make]
+pub struct Alerts {
+    #[serde(rename = "alert", default)]
+    pub alerts: Vec<Alert>,
+}
+
+/// A test method along with its alerts and execution time.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "testMethod")]
+#[readonly::make]
+pub struct TestMethod {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the method, e.g. `CALCULATES_TOTAL`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The URI to navigate to the method implementation in the source code
+    #[serde(rename = "@navigationUri")]
+    pub navigation_uri: Option<String>,
+
+    /// The execution time in the given [`TestMethod::unit`]
+    #[serde(rename = "@executionTime", default)]
+    pub execution_time: f64,
+
+    /// The unit of the execution time, e.g. `s`
+    #[serde(rename = "@unit")]
+    pub unit: Option<String>,
+
+    #[serde(rename = "alerts", default)]
+    pub alerts: Alerts,
+}
+
+impl TestMethod {
+    /// Whether the method passed, i.e. raised no alerts.
+    pub fn passed(&self) -> bool {
+        self.alerts.alerts.is_empty()
+    }
+}
+
+/// Wraps a collection of [`TestMethod`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "testMethods")]
+#[readonly::make]
+pub struct TestMethods {
+    #[serde(rename = "testMethod", default)]
+    pub methods: Vec<TestMethod>,
+}
+
+/// A test class along with its test methods.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "testClass")]
+#[readonly::make]
+pub struct TestClass {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the test class, e.g. `LTCL_DEMO`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The URI to navigate to the test class in the source code
+    #[serde(rename = "@navigationUri")]
+    pub navigation_uri: Option<String>,
+
+    #[serde(rename = "@riskLevel")]
+    pub risk_level: Option<RiskLevel>,
+
+    #[serde(rename = "@durationCategory")]
+    pub duration: Option<TestDuration>,
+
+    /// Alerts of the test class itself, e.g. a failing `class_setup`
+    #[serde(rename = "alerts", default)]
+    pub alerts: Alerts,
+
+    #[serde(rename = "testMethods", default)]
+    pub test_methods: TestMethods,
+}
+
+impl TestClass {
+    /// The summed up execution time of all test methods.
+    pub fn execution_time(&self) -> f64 {
+        self.test_methods
+            .methods
+            .iter()
+            .map(|method| method.execution_time)
+            .sum()
+    }
+}
+
+/// Wraps a collection of [`TestClass`]es
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "testClasses")]
+#[readonly::make]
+pub struct TestClasses {
+    #[serde(rename = "testClass", default)]
+    pub classes: Vec<TestClass>,
+}
+
+/// An object that contains test classes, e.g. a global class or a program.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "program")]
+#[readonly::make]
+pub struct Program {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "testClasses", default)]
+    pub test_classes: TestClasses,
+}
+
+/// Reference to the coverage measured during a test run, see [`RunConfiguration::coverage`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "coverage")]
+#[readonly::make]
+pub struct CoverageRef {
+    /// The URI of the measurement, e.g. `/sap/bc/adt/runtime/traces/coverage/measurements/0242AC11`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "external")]
+#[readonly::make]
+pub struct RunResultExternal {
+    #[serde(rename = "coverage")]
+    pub coverage: Option<CoverageRef>,
+}
+
+/// The result of a test run.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "aunit:runResult")]
+#[readonly::make]
+pub struct RunResult {
+    #[serde(rename = "external", default)]
+    pub external: RunResultExternal,
+
+    #[serde(rename = "program", default)]
+    pub programs: Vec<Program>,
+}
+
+impl RunResult {
+    /// All test methods of all programs and test classes.
+    pub fn test_methods(&self) -> impl Iterator<Item = &TestMethod> {
+        self.programs
+            .iter()
+            .flat_map(|program| program.test_classes.classes.iter())
+            .flat_map(|class| class.test_methods.methods.iter())
+    }
+
+    /// All alerts, including the ones raised outside of test methods.
+    pub fn alerts(&self) -> impl Iterator<Item = &Alert> {
+        let classes = self
+            .programs
+            .iter()
+            .flat_map(|program| program.test_classes.classes.iter());
+        classes.flat_map(|class| {
+            class.alerts.alerts.iter().chain(
+                class
+                    .test_methods
+                    .methods
+                    .iter()
+                    .flat_map(|method| method.alerts.alerts.iter()),
+            )
+        })
+    }
+
+    /// Whether all tests passed without any alerts.
+    pub fn passed(&self) -> bool {
+        self.alerts().next().is_none()
+    }
+
+    /// The measured coverage, only provided if requested in the [`RunConfiguration`].
+    pub fn coverage(&self) -> Option<&CoverageRef> {
+        self.external.coverage.as_ref()
+    }
+}
+
+/// A feature the ABAP Unit runner of the system supports.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Feature {
+    #[serde(rename = "@supported", default)]
+    pub supported: bool,
+}
+
+/// The capabilities of the ABAP Unit runner of the system.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "aunit:metadata")]
+#[readonly::make]
+pub struct Metadata {
+    /// Whether coverage can be measured
+    #[serde(rename = "aunit:coverageMeasurement")]
+    pub coverage: Option<Feature>,
+
+    /// Whether test runs can be executed in the background
+    #[serde(rename = "aunit:asyncExecution")]
+    pub async_execution: Option<Feature>,
+}
+
+/// The objects to fetch the coverage for from a measurement.
+#[derive(Debug, Serialize)]
+#[serde(rename = "cov:query")]
+pub(crate) struct CoverageQuery<'a> {
+    #[serde(rename = "adtcore:objectReferences")]
+    references: UriReferences<'a>,
+}
+
+impl<'a> CoverageQuery<'a> {
+    pub(crate) fn new(uris: &'a [String]) -> Self {
+        Self {
+            references: UriReferences::new(uris),
+        }
+    }
+}
+
+impl IntoXmlRoot for CoverageQuery<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("cov".into(), "http://www.sap.com/adt/cov".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+/// The coverage of a node for one metric, e.g. `statement`, `branch` or `procedure`.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "coverage")]
+#[readonly::make]
+pub struct Coverage {
+    #[serde(rename = "@type")]
+    pub metric: String,
+
+    #[serde(rename = "@total")]
+    pub total: u64,
+
+    #[serde(rename = "@executed")]
+    pub executed: u64,
+}
+
+impl Coverage {
+    /// The executed share in percent, `100` if there is nothing to execute.
+    pub fn percentage(&self) -> f64 {
+        match self.total {
+            0 => 100.0,
+            total => self.executed as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+/// Wraps a collection of [`Coverage`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "coverages")]
+#[readonly::make]
+pub struct Coverages {
+    #[serde(rename = "coverage", default)]
+    pub coverages: Vec<Coverage>,
+}
+
+/// The object a [`CoverageNode`] refers to.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "adtcore:objectReference")]
+#[readonly::make]
+pub struct CoverageObject {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+}
+
+/// A node of the coverage tree, e.g. a package, class or method.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "node")]
+#[readonly::make]
+pub struct CoverageNode {
+    #[serde(rename = "adtcore:objectReference")]
+    pub object: CoverageObject,
+
+    #[serde(rename = "coverages", default)]
+    pub coverages: Coverages,
+
+    #[serde(rename = "nodes", default)]
+    pub nodes: CoverageNodes,
+}
+
+impl CoverageNode {
+    /// The coverage of the given metric, e.g. `statement`
+    pub fn coverage(&self, metric: &str) -> Option<&Coverage> {
+        self.coverages
+            .coverages
+            .iter()
+            .find(|coverage| coverage.metric == metric)
+    }
+}
+
+/// Wraps a collection of [`CoverageNode`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "nodes")]
+#[readonly::make]
+pub struct CoverageNodes {
+    #[serde(rename = "node", default)]
+    pub nodes: Vec<CoverageNode>,
+}
+
+/// The coverage tree of a measurement.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "cov:result")]
+#[readonly::make]
+pub struct CoverageResult {
+    #[serde(rename = "nodes", default)]
+    pub nodes: CoverageNodes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_run_configuration() {
+        let config = RunConfigurationBuilder::default()
+            .object("/sap/bc/adt/oo/classes/zcl_demo")
+            .package("$TMP")
+            .max_risk_level(RiskLevel::Dangerous)
+            .max_duration(TestDuration::Short)
+            .coverage(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <aunit:runConfiguration xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:aunit=\"http://www.sap.com/adt/aunit\">\
+                <external><coverage active=\"true\" /></external>\
+                <options>\
+                    <uriType value=\"semantic\" />\
+                    <testDeterminationStrategy sameProgram=\"true\" assignedTests=\"false\" />\
+                    <testRiskLevels harmless=\"true\" dangerous=\"true\" critical=\"false\" />\
+                    <testDurations short=\"true\" medium=\"false\" long=\"false\" />\
+                    <withNavigationUri enabled=\"true\" />\
+                </options>\
+                <adtcore:objectSets>\
+                    <objectSet kind=\"inclusive\">\
+                        <adtcore:objectReferences>\
+                            <adtcore:objectReference adtcore:uri=\"/sap/bc/adt/oo/classes/zcl_demo\" />\
+                            <adtcore:objectReference adtcore:uri=\"/sap/bc/adt/packages/%24tmp\" />\
+                        </adtcore:objectReferences>\
+                    </objectSet>\
+                </adtcore:objectSets>\
+            </aunit:runConfiguration>"
+        );
+    }
+
+    #[test]
+    fn package_uris_are_encoded() {
+        let config = RunConfigurationBuilder::default()
+            .package("$TMP")
+            .package("/ABC/PKG")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.scope(),
+            [
+                "/sap/bc/adt/packages/%24tmp",
+                "/sap/bc/adt/packages/%2Fabc%2Fpkg"
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_run_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <aunit:runResult xmlns:aunit="http://www.sap.com/adt/aunit">
+                <external>
+                    <coverage xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/runtime/traces/coverage/measurements/0242AC11"/>
+                </external>
+                <program xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO" uriType="semantic">
+                    <testClasses>
+                        <testClass adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#type=CLAS%2FOL;name=LTCL_DEMO" adtcore:type="CLAS/OL" adtcore:name="LTCL_DEMO" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#start=3,0" durationCategory="short" riskLevel="harmless">
+                            <testMethods>
+                                <testMethod adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#type=CLAS%2FOLI;name=LTCL_DEMO;component=ADDS_UP" adtcore:type="CLAS/OLI" adtcore:name="ADDS_UP" executionTime="0.004" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#start=12,0" unit="s"/>
+                                <testMethod adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#type=CLAS%2FOLI;name=LTCL_DEMO;component=FAILS" adtcore:type="CLAS/OLI" adtcore:name="FAILS" executionTime="0.002" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#start=16,0" unit="s">
+                                    <alerts>
+                                        <alert kind="failedAssertion" severity="critical">
+                                            <title>Critical Assertion Error: 'Equals'</title>
+                                            <details>
+                                                <detail text="Expected [3] Actual [4]">
+                                                    <details>
+                                                        <detail text="Test 'LTCL_DEMO-&gt;FAILS' in Main Program 'ZCL_DEMO=====CP'."/>
+                                                    </details>
+                                                </detail>
+                                            </details>
+                                            <stack>
+                                                <stackEntry adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/includes/testclasses#start=18,0" adtcore:type="CLAS/OCN/testclasses" adtcore:name="ZCL_DEMO" adtcore:description="Include: &lt;ZCL_DEMO======CCAU&gt; Line: &lt;18&gt; (FAILS)"/>
+                                            </stack>
+                                        </alert>
+                                    </alerts>
+                                </testMethod>
+                            </testMethods>
+                        </testClass>
+                    </testClasses>
+                </program>
+            </aunit:runResult>"#;
+
+        let result: RunResult = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.programs.len(), 1);
+        assert_eq!(result.test_methods().count(), 2);
+        assert!(!result.passed());
+
+        let class = &result.programs[0].test_classes.classes[0];
+        assert_eq!(class.risk_level, Some(RiskLevel::Harmless));
+        assert_eq!(class.duration, Some(TestDuration::Short));
+        assert!((class.execution_time() - 0.006).abs() < f64::EPSILON);
+
+        let alert = result.alerts().next().unwrap();
+        assert_eq!(alert.kind, AlertKind::FailedAssertion);
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert_eq!(alert.details.details[0].details.details.len(), 1);
+        assert_eq!(alert.stack.entries.len(), 1);
+        assert_eq!(
+            result.coverage().map(|coverage| coverage.uri.as_str()),
+            Some("/sap/bc/adt/runtime/traces/coverage/measurements/0242AC11")
+        );
+    }
+
+    #[test]
+    fn deserialize_coverage_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <cov:result xmlns:cov="http://www.sap.com/adt/cov" xmlns:adtcore="http://www.sap.com/adt/core">
+                <nodes>
+                    <node>
+                        <adtcore:objectReference adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO"/>
+                        <coverages>
+                            <coverage type="branch" total="4" executed="3"/>
+                            <coverage type="procedure" total="2" executed="2"/>
+                            <coverage type="statement" total="10" executed="8"/>
+                        </coverages>
+                        <nodes>
+                            <node>
+                                <adtcore:objectReference adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/source/main#start=12,2" adtcore:type="CLAS/OM" adtcore:name="ADD"/>
+                                <coverages>
+                                    <coverage type="statement" total="0" executed="0"/>
+                                </coverages>
+                            </node>
+                        </nodes>
+                    </node>
+                </nodes>
+            </cov:result>"#;
+
+        let result: CoverageResult = serde_xml_rs::from_str(plain).unwrap();
+        let class = &result.nodes.nodes[0];
+        assert_eq!(
+            class.coverage("statement").map(|c| c.percentage()),
+            Some(80.0)
+        );
+        assert_eq!(class.coverage("branch").map(|c| c.percentage()), Some(75.0));
+        assert_eq!(
+            class.nodes.nodes[0]
+                .coverage("statement")
+                .map(|c| c.percentage()),
+            Some(100.0)
+        );
+    }
+
+    #[test]
+    fn deserialize_metadata() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <aunit:metadata xmlns:aunit="http://www.sap.com/adt/aunit">
+                <aunit:coverageMeasurement supported="true"/>
+                <aunit:asyncExecution supported="false"/>
+            </aunit:metadata>"#;
+
+        let result: Metadata = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.coverage.map(|c| c.supported).unwrap_or_default());
+        assert!(!result.async_execution.map(|c| c.supported).unwrap_or(true));
+    }
+}
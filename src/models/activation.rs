@@ -0,0 +1,477 @@
+/// Activation - http://www.sap.com/abapxml/checklist
+///
+/// Covers the objects to activate, the messages reported by the activation and the
+/// inactive objects of the system (http://www.sap.com/abapxml/inactiveCtsObjects).
+use crate::{
+    error::ResponseError,
+    models::{
+        abapsource::{Severity, SourceLocation},
+        atom,
+        serialize::IntoXmlRoot,
+    },
+    response::DeserializeResponse,
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// An object to be activated.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::activation::ActivationObjectBuilder;
+///
+/// ActivationObjectBuilder::default()
+///     .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+///     .name("ZWEGWERF1")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Debug, Serialize, Clone)]
+#[serde(rename = "adtcore:objectReference")]
+pub struct ActivationObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    #[builder(setter(into))]
+    uri: String,
+
+    /// The name of the object, e.g. `ZWEGWERF1`
+    #[serde(rename = "@adtcore:name")]
+    #[builder(setter(into))]
+    name: String,
+}
+
+impl ActivationObject {
+    pub fn new<T: Into<String>, U: Into<String>>(uri: T, name: U) -> Self {
+        Self {
+            uri: uri.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl From<&InactiveRef> for ActivationObject {
+    fn from(value: &InactiveRef) -> Self {
+        Self {
+            uri: value.uri.clone(),
+            name: value.name.clone(),
+        }
+    }
+}
+
+/// Wraps a collection of [`ActivationObject`]s
+///
+/// Typically the root element of a XML Body.
+#[derive(Builder, Debug, Serialize, Clone, Default)]
+#[serde(rename = "adtcore:objectReferences")]
+pub struct ActivationObjects {
+    #[serde(rename = "adtcore:objectReference")]
+    #[builder(setter(each(name = "object", into)))]
+    objects: Vec<ActivationObject>,
+}
+
+impl ActivationObjects {
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+}
+
+impl IntoXmlRoot for ActivationObjects {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("adtcore".into(), "http://www.sap.com/adt/core".into())]
+    }
+}
+
+/// The text of an [`ActivationMessage`], may be split across multiple lines.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct MessageText {
+    #[serde(rename = "txt", default)]
+    pub lines: Vec<String>,
+}
+
+/// A message reported during activation, e.g. a syntax error in one of the objects.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "msg")]
+#[readonly::make]
+pub struct ActivationMessage {
+    /// The severity of the message
+    #[serde(rename = "@type")]
+    pub severity: Severity,
+
+    /// The description of the object the message refers to, e.g. `Program ZWEGWERF1`
+    #[serde(rename = "@objDescr")]
+    pub object_description: Option<String>,
+
+    /// The line the message refers to
+    #[serde(rename = "@line")]
+    pub line: Option<u32>,
+
+    /// The location the message refers to, e.g. `/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=5,0`
+    #[serde(rename = "@href")]
+    pub href: Option<String>,
+
+    /// Whether the activation can be forced despite of this message
+    #[serde(rename = "@forceSupported", default)]
+    pub force_supported: bool,
+
+    #[serde(rename = "shortText")]
+    pub short_text: MessageText,
+}
+
+impl ActivationMessage {
+    /// The text of the message as a single line.
+    pub fn text(&self) -> String {
+        self.short_text.lines.join(" ")
+    }
+
+    /// The position in the source code the message refers to, if it refers to one.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.href.as_deref().and_then(|href| href.parse().ok())
+    }
+}
+
+/// Which steps of the activation were executed.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "properties")]
+#[readonly::make]
+pub struct ActivationProperties {
+    #[serde(rename = "@checkExecuted", default)]
+    pub check_executed: bool,
+
+    #[serde(rename = "@activationExecuted", default)]
+    pub activation_executed: bool,
+
+    #[serde(rename = "@generationExecuted", default)]
+    pub generation_executed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "chkl:messages")]
+struct MessagesBody {
+    #[serde(rename = "msg", default)]
+    messages: Vec<ActivationMessage>,
+
+    #[serde(rename = "properties")]
+    properties: Option<ActivationProperties>,
+}
+
+/// The result of an activation.
+///
+/// The server answers with an empty body if everything was activated, with a list of messages
+/// if something went wrong, or with a list of [`InactiveObjects`] if the pre-audit found objects
+/// that have to be activated together with the requested ones.
+#[derive(Debug, Default)]
+#[readonly::make]
+pub struct ActivationResult {
+    /// Errors, warnings and informational messages of the activation
+    pub messages: Vec<ActivationMessage>,
+
+    /// Which steps of the activation were executed, not provided for an empty result.
+    pub properties: Option<ActivationProperties>,
+
+    /// Objects that have to be activated along with the requested objects.
+    pub pending: Vec<InactiveEntry>,
+}
+
+impl ActivationResult {
+    /// Whether the objects were activated, i.e. no errors occurred and nothing is pending.
+    pub fn is_activated(&self) -> bool {
+        self.pending.is_empty()
+            && self
+                .properties
+                .as_ref()
+                .is_none_or(|properties| properties.activation_executed)
+            && !self.messages.iter().any(|msg| msg.severity.is_error())
+    }
+
+    /// The messages that prevented the activation.
+    pub fn errors(&self) -> impl Iterator<Item = &ActivationMessage> {
+        self.messages.iter().filter(|msg| msg.severity.is_error())
+    }
+}
+
+impl DeserializeResponse for ActivationResult {
+    fn deserialize_response(body: String) -> Result<Self, ResponseError> {
+        if body.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        if body.contains("ioc:inactiveObjects") {
+            let inactive: InactiveObjects = serde_xml_rs::from_str(&body)?;
+            return Ok(Self {
+                pending: inactive.entries,
+                ..Default::default()
+            });
+        }
+        let body: MessagesBody = serde_xml_rs::from_str(&body)?;
+        Ok(Self {
+            messages: body.messages,
+            properties: body.properties,
+            pending: Vec::new(),
+        })
+    }
+}
+
+/// A reference to an inactive object or the transport it is recorded in.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:ref")]
+#[readonly::make]
+pub struct InactiveRef {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the object, e.g. `PROG/P`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZWEGWERF1`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The URI of the parent, e.g. the class of a method
+    #[serde(rename = "@adtcore:parentUri")]
+    pub parent_uri: Option<String>,
+
+    /// The description, only provided for transports
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+}
+
+/// An inactive object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:object")]
+#[readonly::make]
+pub struct InactiveObject {
+    /// The user who last changed the object
+    #[serde(rename = "@ioc:user")]
+    pub user: String,
+
+    /// Whether the object was deleted and the deletion is yet to be activated
+    #[serde(rename = "@ioc:deleted", default)]
+    pub deleted: bool,
+
+    #[serde(rename = "ioc:ref")]
+    pub reference: InactiveRef,
+}
+
+/// The transport an inactive object is recorded in.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:transport")]
+#[readonly::make]
+pub struct InactiveTransport {
+    /// The owner of the transport
+    #[serde(rename = "@ioc:user")]
+    pub user: String,
+
+    /// To be clarified
+    #[serde(rename = "@ioc:linked", default)]
+    pub linked: bool,
+
+    #[serde(rename = "ioc:ref")]
+    pub reference: InactiveRef,
+}
+
+/// An entry of the inactive objects list, either an object or the transport it belongs to.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:entry")]
+#[readonly::make]
+pub struct InactiveEntry {
+    #[serde(rename = "ioc:object")]
+    pub object: Option<InactiveObject>,
+
+    #[serde(rename = "ioc:transport")]
+    pub transport: Option<InactiveTransport>,
+}
+
+/// Wraps a collection of [`InactiveEntry`]s
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:inactiveObjects")]
+#[readonly::make]
+pub struct InactiveObjects {
+    #[serde(rename = "ioc:entry", default)]
+    pub entries: Vec<InactiveEntry>,
+}
+
+impl InactiveObjects {
+    /// All inactive objects, leaving out the transports.
+    pub fn objects(&self) -> impl Iterator<Item = &InactiveObject> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.object.as_ref())
+    }
+
+    /// The inactive objects last changed by the given user.
+    pub fn objects_of<'a>(&'a self, user: &'a str) -> impl Iterator<Item = &'a InactiveObject> {
+        self.objects()
+            .filter(move |object| object.user.eq_ignore_ascii_case(user))
+    }
+}
+
+/// The progress of a background run.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "run:progress")]
+#[readonly::make]
+pub struct RunProgress {
+    /// The progress in percent
+    #[serde(rename = "@run:percentage", default)]
+    pub percentage: u32,
+
+    /// What the run is currently doing
+    #[serde(rename = "@run:text")]
+    pub text: Option<String>,
+}
+
+/// The state of an asynchronous activation run.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "run:run")]
+#[readonly::make]
+pub struct ActivationRun {
+    /// The id of the run, used to poll its state and fetch its result.
+    #[serde(rename = "@run:id")]
+    pub id: String,
+
+    /// The state of the run, e.g. `running` or `finished`
+    #[serde(rename = "@run:status")]
+    pub status: String,
+
+    #[serde(rename = "run:progress")]
+    pub progress: Option<RunProgress>,
+
+    /// Links to related operations, such as the result of the run
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl ActivationRun {
+    /// Whether the run finished and the result can be fetched.
+    pub fn is_finished(&self) -> bool {
+        self.status.eq_ignore_ascii_case("finished")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::abapsource::Position;
+
+    #[test]
+    fn serialize_activation_objects() {
+        let objects = ActivationObjectsBuilder::default()
+            .object(
+                ActivationObjectBuilder::default()
+                    .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                    .name("ZWEGWERF1")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            objects.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <adtcore:objectReferences xmlns:adtcore=\"http://www.sap.com/adt/core\">\
+                <adtcore:objectReference adtcore:uri=\"/sap/bc/adt/programs/programs/zwegwerf1\" adtcore:name=\"ZWEGWERF1\" />\
+            </adtcore:objectReferences>"
+        );
+    }
+
+    #[test]
+    fn deserialize_activation_messages() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <chkl:messages xmlns:chkl="http://www.sap.com/abapxml/checklist">
+                <msg objDescr="Program ZWEGWERF1" type="E" line="1" href="/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=5,0" forceSupported="true">
+                    <shortText>
+                        <txt>The statement "WRTE" is invalid.</txt>
+                    </shortText>
+                </msg>
+                <msg objDescr="Program ZWEGWERF1" type="W" line="1" href="/sap/bc/adt/programs/programs/zwegwerf1" forceSupported="false">
+                    <shortText>
+                        <txt>Program ZWEGWERF1 was not activated.</txt>
+                    </shortText>
+                </msg>
+                <properties checkExecuted="true" activationExecuted="false" generationExecuted="false"/>
+            </chkl:messages>"#;
+
+        let result = ActivationResult::deserialize_response(plain.to_string()).unwrap();
+        assert_eq!(result.messages.len(), 2);
+        assert!(!result.is_activated());
+        assert_eq!(result.errors().count(), 1);
+        assert_eq!(
+            result.messages[0].location().map(|location| location.start),
+            Some(Position::new(5, 0))
+        );
+        assert_eq!(
+            result.messages[0].text(),
+            "The statement \"WRTE\" is invalid."
+        );
+        assert_eq!(result.messages[1].severity, Severity::Warning);
+        assert_eq!(result.messages[1].location(), None);
+    }
+
+    #[test]
+    fn empty_activation_result_is_activated() {
+        let result = ActivationResult::deserialize_response(String::new()).unwrap();
+        assert!(result.is_activated());
+    }
+
+    #[test]
+    fn deserialize_inactive_objects() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <ioc:inactiveObjects xmlns:ioc="http://www.sap.com/abapxml/inactiveCtsObjects" xmlns:adtcore="http://www.sap.com/adt/core">
+                <ioc:entry>
+                    <ioc:object ioc:user="DEVELOPER" ioc:deleted="false">
+                        <ioc:ref adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1"/>
+                    </ioc:object>
+                </ioc:entry>
+                <ioc:entry>
+                    <ioc:object ioc:user="OTHER" ioc:deleted="false">
+                        <ioc:ref adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo1" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO1"/>
+                    </ioc:object>
+                    <ioc:transport ioc:user="OTHER" ioc:linked="false">
+                        <ioc:ref adtcore:uri="/sap/bc/adt/cts/transportrequests/A4HK900089" adtcore:type="/RQ" adtcore:name="A4HK900089" adtcore:description="Demo"/>
+                    </ioc:transport>
+                </ioc:entry>
+            </ioc:inactiveObjects>"#;
+
+        let result: InactiveObjects = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.entries.len(), 2);
+        assert_eq!(result.objects().count(), 2);
+
+        let mine: Vec<_> = result.objects_of("developer").collect();
+        assert_eq!(mine.len(), 1);
+        assert_eq!(mine[0].reference.name, "ZWEGWERF1");
+    }
+
+    #[test]
+    fn pending_objects_are_reported() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <ioc:inactiveObjects xmlns:ioc="http://www.sap.com/abapxml/inactiveCtsObjects" xmlns:adtcore="http://www.sap.com/adt/core">
+                <ioc:entry>
+                    <ioc:object ioc:user="DEVELOPER">
+                        <ioc:ref adtcore:uri="/sap/bc/adt/programs/includes/zwegwerf1_top" adtcore:type="PROG/I" adtcore:name="ZWEGWERF1_TOP"/>
+                    </ioc:object>
+                </ioc:entry>
+            </ioc:inactiveObjects>"#;
+
+        let result = ActivationResult::deserialize_response(plain.to_string()).unwrap();
+        assert_eq!(result.pending.len(), 1);
+        assert!(!result.is_activated());
+    }
+
+    #[test]
+    fn deserialize_activation_run() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <run:run xmlns:run="http://www.sap.com/adt/backgroundrun" run:id="0242AC1100021EDF" run:status="finished">
+                <run:progress run:percentage="100" run:text="Activation finished"/>
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="/sap/bc/adt/activation/results/0242AC1100021EDF" rel="http://www.sap.com/adt/relations/runs/result"/>
+            </run:run>"#;
+
+        let result: ActivationRun = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.is_finished());
+        assert_eq!(result.progress.map(|p| p.percentage), Some(100));
+    }
+}
@@ -1,5 +1,13 @@
 use serde::Deserialize;
 
+use crate::{
+    error::TemplateError,
+    uritemplate::{TemplateVariables, UriTemplate},
+};
+
+/// A link to a resource whose URI is described by a [RFC 6570][rfc] template.
+///
+/// [rfc]: https://datatracker.ietf.org/doc/html/rfc6570
 #[derive(Debug, Deserialize)]
 #[serde(rename = "adtcomp:templateLink")]
 #[readonly::make]
@@ -10,13 +18,27 @@ pub struct TemplateLink {
     #[serde(rename = "@rel")]
     pub relation: String,
 
+    /// The URI template, e.g. `/sap/bc/adt/repository/informationsystem/properties/values?data=owner{&name}`
     #[serde(rename = "@template")]
     pub template: String,
 
+    /// The content type of the linked resource, e.g. `application/vnd.sap.adt.nameditems.v1+xml`
     #[serde(rename = "@type")]
     pub content_type: String,
 }
 
+impl TemplateLink {
+    /// Parses the template of this link, see [`UriTemplate`]
+    pub fn uri_template(&self) -> Result<UriTemplate, TemplateError> {
+        self.template.parse()
+    }
+
+    /// Expands the template of this link into a URI with the provided variables.
+    pub fn expand(&self, variables: &TemplateVariables) -> Result<String, TemplateError> {
+        Ok(self.uri_template()?.expand(variables))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,4 +50,22 @@ mod tests {
         let result: TemplateLink = serde_xml_rs::from_str(plain).unwrap();
         println!("{:?}", result);
     }
+
+    #[test]
+    fn expand_template_link() {
+        let plain = r#"<adtcomp:templateLink xmlns:adtcomp="http://www.sap.com/adt/compatibility" title="Owners" rel="http://www.sap.com/adt/relations/informationsystem/propertyvalues" template="/sap/bc/adt/repository/informationsystem/properties/values?data=owner{&amp;name}" type="application/vnd.sap.adt.nameditems.v1+xml"/>"#;
+        let link: TemplateLink = serde_xml_rs::from_str(plain).unwrap();
+
+        let mut variables = TemplateVariables::new();
+        assert_eq!(
+            link.expand(&variables).unwrap(),
+            "/sap/bc/adt/repository/informationsystem/properties/values?data=owner"
+        );
+
+        variables.set("name", "DEVELOPER");
+        assert_eq!(
+            link.expand(&variables).unwrap(),
+            "/sap/bc/adt/repository/informationsystem/properties/values?data=owner&name=DEVELOPER"
+        );
+    }
 }
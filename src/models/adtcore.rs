@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use serde::{Deserialize, Serialize};
 
-use crate::ParamValue;
+use crate::{ParamValue, models::abapsource::SourceLocation};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "adtcore:packageRef")]
@@ -18,6 +18,115 @@ pub struct PackageRef {
     pub object_type: String,
 }
 
+/// A reference to a repository object, used whenever the server lists objects.
+///
+/// XML Example:
+/// ```xml
+/// <adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1" adtcore:type="PROG/P"
+///     adtcore:name="ZWEGWERF1" adtcore:packageName="$TMP" adtcore:description="test"/>
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(rename = "adtcore:objectReference")]
+#[readonly::make]
+pub struct ObjectReference {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the object, e.g. `PROG/P` or `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZWEGWERF1`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The package the object is assigned to, not provided for all kinds of objects.
+    #[serde(rename = "@adtcore:packageName")]
+    pub package_name: Option<String>,
+
+    /// The short description of the object
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+
+    /// The URI of the parent object, e.g. the class of a method.
+    #[serde(rename = "@adtcore:parentUri")]
+    pub parent_uri: Option<String>,
+}
+
+impl ObjectReference {
+    /// The position the reference points to, if its URI contains one.
+    ///
+    /// Provided for navigation targets, e.g. `…/zcl_demo/source/main#start=12,4`
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.uri.parse().ok()
+    }
+}
+
+/// Wraps a collection of [`ObjectReference`]s
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "adtcore:objectReferences")]
+#[readonly::make]
+pub struct ObjectReferences {
+    #[serde(rename = "adtcore:objectReference", default)]
+    pub references: Vec<ObjectReference>,
+}
+
+/// A reference to an object by its URI only, used in request bodies.
+#[derive(Debug, Serialize)]
+#[serde(rename = "adtcore:objectReference")]
+pub(crate) struct UriReference<'a> {
+    #[serde(rename = "@adtcore:uri")]
+    uri: &'a str,
+}
+
+impl<'a> UriReference<'a> {
+    pub(crate) fn new(uri: &'a str) -> Self {
+        Self { uri }
+    }
+}
+
+/// Wraps a collection of [`UriReference`]s
+#[derive(Debug, Serialize)]
+#[serde(rename = "adtcore:objectReferences")]
+pub(crate) struct UriReferences<'a> {
+    #[serde(rename = "adtcore:objectReference")]
+    references: Vec<UriReference<'a>>,
+}
+
+impl<'a> UriReferences<'a> {
+    pub(crate) fn new<T: AsRef<str>>(uris: &'a [T]) -> Self {
+        Self {
+            references: uris
+                .iter()
+                .map(|uri| UriReference::new(uri.as_ref()))
+                .collect(),
+        }
+    }
+}
+
+/// An inclusive set of objects, e.g. the objects to run tests or checks on.
+#[derive(Debug, Serialize)]
+#[serde(rename = "objectSet")]
+pub(crate) struct ObjectSet<'a> {
+    #[serde(rename = "@kind")]
+    kind: &'static str,
+
+    #[serde(rename = "adtcore:objectReferences")]
+    references: UriReferences<'a>,
+}
+
+impl<'a> ObjectSet<'a> {
+    pub(crate) fn inclusive<T: AsRef<str>>(uris: &'a [T]) -> Self {
+        Self {
+            kind: "inclusive",
+            references: UriReferences::new(uris),
+        }
+    }
+}
+
 /// Reflects DDIC type `SADT_OBJ_VERSION` for object version management.
 ///
 /// Is used for classes, programs and other objects alike. Documentation is lacking..
@@ -59,3 +168,45 @@ impl<'a> ParamValue<'a> for Version {
         Cow::Borrowed(self.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_object_references() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <adtcore:objectReferences xmlns:adtcore="http://www.sap.com/adt/core">
+                        <adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1" adtcore:packageName="$TMP" adtcore:description="test"/>
+                        <adtcore:objectReference adtcore:uri="/sap/bc/adt/oo/classes/zcl_wegwerf" adtcore:type="CLAS/OC" adtcore:name="ZCL_WEGWERF" adtcore:packageName="$TMP"/>
+                    </adtcore:objectReferences>"#;
+
+        let result: ObjectReferences = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.references.len(), 2);
+        assert_eq!(result.references[0].object_type, "PROG/P");
+        assert_eq!(result.references[0].package_name.as_deref(), Some("$TMP"));
+        assert_eq!(result.references[1].description, None);
+    }
+
+    #[test]
+    fn deserialize_empty_object_references() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?><adtcore:objectReferences xmlns:adtcore="http://www.sap.com/adt/core"/>"#;
+
+        let result: ObjectReferences = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.references.is_empty());
+    }
+
+    #[test]
+    fn location_of_navigation_target() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?><adtcore:objectReference xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo/source/main#start=12,4" adtcore:type="CLAS/OM" adtcore:name="RUN" adtcore:parentUri="/sap/bc/adt/oo/classes/zcl_demo"/>"#;
+
+        let reference: ObjectReference = serde_xml_rs::from_str(plain).unwrap();
+        let location = reference.location().unwrap();
+        assert_eq!(location.uri, "/sap/bc/adt/oo/classes/zcl_demo/source/main");
+        assert_eq!(location.start.line, 12);
+        assert_eq!(
+            reference.parent_uri.as_deref(),
+            Some("/sap/bc/adt/oo/classes/zcl_demo")
+        );
+    }
+}
@@ -88,6 +88,199 @@ pub struct LockResult {
     pub scope_messages: String,
 }
 
+impl LockResult {
+    /// Returns the transport the object is locked in, if it is locked in one.
+    pub fn transport(&self) -> Option<&str> {
+        Some(self.transport_number.as_str()).filter(|number| !number.is_empty())
+    }
+}
+
+/// Contains the nodes of a repository tree, e.g. the content of a package.
+///
+/// Content Type Version `com.sap.adt.RepositoryObjectTreeContent`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "DATA")]
+#[readonly::make]
+pub struct NodeStructure {
+    /// The nodes directly below the requested parent node.
+    #[serde(rename = "TREE_CONTENT", default)]
+    pub nodes: TreeContent,
+
+    /// The categories the object types of the nodes belong to, e.g. `source_library`.
+    #[serde(rename = "CATEGORIES", default)]
+    pub categories: Categories,
+
+    /// The object types that occur below the requested parent node.
+    #[serde(rename = "OBJECT_TYPES", default)]
+    pub object_types: ObjectTypes,
+}
+
+impl NodeStructure {
+    /// Returns all nodes that are packages, i.e. of type `DEVC/K`.
+    pub fn sub_packages(&self) -> impl Iterator<Item = &RepositoryNode> {
+        self.nodes
+            .nodes
+            .iter()
+            .filter(|node| node.object_type == "DEVC/K")
+    }
+
+    /// Returns all nodes of the given object type, e.g. `FUGR/FF` for function modules.
+    pub fn nodes_of_type<'a>(
+        &'a self,
+        object_type: &'a str,
+    ) -> impl Iterator<Item = &'a RepositoryNode> {
+        self.nodes
+            .nodes
+            .iter()
+            .filter(move |node| node.object_type == object_type)
+    }
+
+    /// Returns all nodes that are not packages.
+    pub fn objects(&self) -> impl Iterator<Item = &RepositoryNode> {
+        self.nodes
+            .nodes
+            .iter()
+            .filter(|node| node.object_type != "DEVC/K")
+    }
+}
+
+/// Wraps the collection of [`RepositoryNode`]s
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct TreeContent {
+    #[serde(rename = "SEU_ADT_REPOSITORY_OBJ_NODE", default)]
+    pub nodes: Vec<RepositoryNode>,
+}
+
+/// A single node of a repository tree.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SEU_ADT_REPOSITORY_OBJ_NODE")]
+#[readonly::make]
+pub struct RepositoryNode {
+    /// The object type of the node, e.g. `DEVC/K` or `PROG/P`
+    #[serde(rename = "OBJECT_TYPE")]
+    pub object_type: String,
+
+    /// The (display) name of the object
+    #[serde(rename = "OBJECT_NAME")]
+    pub object_name: String,
+
+    /// The technical name of the object
+    #[serde(rename = "TECH_NAME")]
+    pub tech_name: String,
+
+    /// The ADT URI of the object, e.g. `/sap/bc/adt/programs/programs/z_test`
+    #[serde(rename = "OBJECT_URI")]
+    pub object_uri: String,
+
+    /// The URI to the object in the SAP GUI.
+    #[serde(rename = "OBJECT_VIT_URI")]
+    pub object_vit_uri: Option<String>,
+
+    /// Whether the node can be expanded, i.e. has children.
+    #[serde(
+        rename = "EXPANDABLE",
+        deserialize_with = "deserialize_abap_bool",
+        default
+    )]
+    pub expandable: bool,
+
+    /// The description of the object
+    #[serde(rename = "DESCRIPTION")]
+    pub description: Option<String>,
+
+    /// The identifier of the node within the tree.
+    #[serde(rename = "NODE_ID")]
+    pub node_id: Option<String>,
+}
+
+/// Wraps the collection of [`Category`]s
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct Categories {
+    #[serde(rename = "SEU_ADT_OBJECT_CATEGORY_INFO", default)]
+    pub categories: Vec<Category>,
+}
+
+/// A category of object types in the repository tree.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SEU_ADT_OBJECT_CATEGORY_INFO")]
+#[readonly::make]
+pub struct Category {
+    /// The technical name of the category, e.g. `source_library`
+    #[serde(rename = "CATEGORY")]
+    pub category: String,
+
+    /// The label of the category, e.g. `Source Code Library`
+    #[serde(rename = "CATEGORY_LABEL")]
+    pub label: String,
+}
+
+/// Wraps the collection of [`ObjectTypeInfo`]s
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct ObjectTypes {
+    #[serde(rename = "SEU_ADT_OBJECT_TYPE_INFO", default)]
+    pub object_types: Vec<ObjectTypeInfo>,
+}
+
+/// An object type occuring in the repository tree.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SEU_ADT_OBJECT_TYPE_INFO")]
+#[readonly::make]
+pub struct ObjectTypeInfo {
+    /// The object type, e.g. `PROG/P`
+    #[serde(rename = "OBJECT_TYPE")]
+    pub object_type: String,
+
+    /// The category the object type belongs to, see [`Category`]
+    #[serde(rename = "CATEGORY_TAG")]
+    pub category: String,
+
+    /// The label of the object type, e.g. `Programs`
+    #[serde(rename = "OBJECT_TYPE_LABEL")]
+    pub label: String,
+
+    /// The identifier of the node within the tree.
+    #[serde(rename = "NODE_ID")]
+    pub node_id: Option<String>,
+}
+
+/// Contains the result of a validation, e.g. of a new object name.
+///
+/// Content Type Version `com.sap.adt.validationMessages`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "DATA")]
+#[readonly::make]
+pub struct ValidationResult {
+    /// `X` if the validation succeeded without any findings.
+    #[serde(
+        rename = "CHECK_RESULT",
+        deserialize_with = "deserialize_abap_bool",
+        default
+    )]
+    pub check_result: bool,
+
+    /// The severity of the finding, e.g. `ERROR` or `WARNING`
+    #[serde(rename = "SEVERITY")]
+    pub severity: Option<String>,
+
+    /// Short text of the finding
+    #[serde(rename = "SHORT_TEXT")]
+    pub short_text: Option<String>,
+
+    /// Long text of the finding
+    #[serde(rename = "LONG_TEXT")]
+    pub long_text: Option<String>,
+}
+
+impl ValidationResult {
+    /// Whether the validation passed, warnings do not cause it to fail.
+    pub fn is_ok(&self) -> bool {
+        self.check_result || !matches!(self.severity.as_deref(), Some("ERROR") | Some("FATAL"))
+    }
+}
+
 /// Deserialize `X` to `true` and all other values to `false`.
 pub fn deserialize_abap_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
@@ -128,6 +321,7 @@ mod tests {
                     "#;
         let result: AsxData<LockResult> = serde_xml_rs::from_str(&plain).unwrap();
         assert_eq!(result.is_local, true);
+        assert_eq!(result.transport(), None);
         assert_eq!(
             result.lock_handle,
             "77D4511AAADBBE2691139283AA9D03A250C1FB22"
@@ -158,5 +352,89 @@ mod tests {
         let result: AsxData<LockResult> = serde_xml_rs::from_str(&plain).unwrap();
         assert_eq!(result.is_local, false);
         assert_eq!(result.transport_number, "A4HK900089");
+        assert_eq!(result.transport(), Some("A4HK900089"));
+    }
+
+    #[test]
+    fn deserialize_node_structure() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                    <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                        <asx:values>
+                            <DATA>
+                                <TREE_CONTENT>
+                                    <SEU_ADT_REPOSITORY_OBJ_NODE>
+                                        <OBJECT_TYPE>DEVC/K</OBJECT_TYPE>
+                                        <OBJECT_NAME>$TMP_SUB</OBJECT_NAME>
+                                        <TECH_NAME>$TMP_SUB</TECH_NAME>
+                                        <OBJECT_URI>/sap/bc/adt/packages/%24tmp_sub</OBJECT_URI>
+                                        <OBJECT_VIT_URI>/sap/bc/adt/vit/wb/object_type/devck/object_name/%24TMP_SUB</OBJECT_VIT_URI>
+                                        <EXPANDABLE>X</EXPANDABLE>
+                                        <DESCRIPTION>Sub Package</DESCRIPTION>
+                                        <NODE_ID>000001</NODE_ID>
+                                    </SEU_ADT_REPOSITORY_OBJ_NODE>
+                                    <SEU_ADT_REPOSITORY_OBJ_NODE>
+                                        <OBJECT_TYPE>PROG/P</OBJECT_TYPE>
+                                        <OBJECT_NAME>Z_TEST</OBJECT_NAME>
+                                        <TECH_NAME>Z_TEST</TECH_NAME>
+                                        <OBJECT_URI>/sap/bc/adt/programs/programs/z_test</OBJECT_URI>
+                                        <OBJECT_VIT_URI/>
+                                        <EXPANDABLE/>
+                                        <DESCRIPTION>Test Program</DESCRIPTION>
+                                        <NODE_ID/>
+                                    </SEU_ADT_REPOSITORY_OBJ_NODE>
+                                </TREE_CONTENT>
+                                <CATEGORIES>
+                                    <SEU_ADT_OBJECT_CATEGORY_INFO>
+                                        <CATEGORY>source_library</CATEGORY>
+                                        <CATEGORY_LABEL>Source Code Library</CATEGORY_LABEL>
+                                    </SEU_ADT_OBJECT_CATEGORY_INFO>
+                                </CATEGORIES>
+                                <OBJECT_TYPES>
+                                    <SEU_ADT_OBJECT_TYPE_INFO>
+                                        <OBJECT_TYPE>PROG/P</OBJECT_TYPE>
+                                        <CATEGORY_TAG>source_library</CATEGORY_TAG>
+                                        <OBJECT_TYPE_LABEL>Programs</OBJECT_TYPE_LABEL>
+                                        <NODE_ID>000002</NODE_ID>
+                                    </SEU_ADT_OBJECT_TYPE_INFO>
+                                </OBJECT_TYPES>
+                            </DATA>
+                        </asx:values>
+                    </asx:abap>
+                    "#;
+        let result: AsxData<NodeStructure> = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.nodes.nodes.len(), 2);
+        assert_eq!(result.sub_packages().count(), 1);
+        assert!(result.nodes.nodes[0].expandable);
+        assert_eq!(result.objects().next().unwrap().object_name, "Z_TEST");
+        assert_eq!(result.object_types.object_types[0].label, "Programs");
+    }
+
+    #[test]
+    fn deserialize_validation_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                    <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                        <asx:values>
+                            <DATA>
+                                <SEVERITY>ERROR</SEVERITY>
+                                <SHORT_TEXT>Package ZMY_PACKAGE already exists</SHORT_TEXT>
+                                <LONG_TEXT/>
+                            </DATA>
+                        </asx:values>
+                    </asx:abap>
+                    "#;
+        let result: AsxData<ValidationResult> = serde_xml_rs::from_str(plain).unwrap();
+        assert!(!result.is_ok());
+
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                    <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                        <asx:values>
+                            <DATA>
+                                <CHECK_RESULT>X</CHECK_RESULT>
+                            </DATA>
+                        </asx:values>
+                    </asx:abap>
+                    "#;
+        let result: AsxData<ValidationResult> = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.is_ok());
     }
 }
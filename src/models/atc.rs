@@ -0,0 +1,575 @@
+/// ABAP Test Cockpit (ATC) - http://www.sap.com/adt/atc
+///
+/// Covers the customizing of the ATC, runs on object sets, the resulting worklists with
+/// their findings and requesting exemptions for findings.
+use crate::models::{
+    abapsource::{Severity, SourceLocation},
+    adtcore::ObjectSet,
+    atom,
+    serialize::IntoXmlRoot,
+};
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A property of the ATC customizing, e.g. `systemCheckVariant`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "property")]
+#[readonly::make]
+pub struct Property {
+    #[serde(rename = "@name")]
+    pub name: String,
+
+    #[serde(rename = "@value")]
+    pub value: String,
+}
+
+/// Wraps a collection of [`Property`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "properties")]
+#[readonly::make]
+pub struct Properties {
+    #[serde(rename = "property", default)]
+    pub properties: Vec<Property>,
+}
+
+/// A reason an exemption can be requested for, e.g. `FPOS` for a false positive.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "reason")]
+#[readonly::make]
+pub struct ExemptionReason {
+    #[serde(rename = "@id")]
+    pub id: String,
+
+    #[serde(rename = "@title")]
+    pub title: String,
+
+    /// Whether a justification has to be given when requesting an exemption
+    #[serde(rename = "@justificationMandatory", default)]
+    pub justification_mandatory: bool,
+}
+
+/// Wraps a collection of [`ExemptionReason`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "reasons")]
+#[readonly::make]
+pub struct ExemptionReasons {
+    #[serde(rename = "reason", default)]
+    pub reasons: Vec<ExemptionReason>,
+}
+
+/// The exemption settings of the ATC customizing.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "exemption")]
+#[readonly::make]
+pub struct ExemptionCustomizing {
+    #[serde(rename = "reasons", default)]
+    pub reasons: ExemptionReasons,
+}
+
+/// The ATC customizing of the system, e.g. the check variant used by default.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atccust:customizing")]
+#[readonly::make]
+pub struct Customizing {
+    #[serde(rename = "properties", default)]
+    pub properties: Properties,
+
+    #[serde(rename = "exemption", default)]
+    pub exemption: ExemptionCustomizing,
+}
+
+impl Customizing {
+    /// The value of a customizing property, e.g. `systemCheckVariant`
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .properties
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| property.value.as_str())
+    }
+
+    /// The check variant runs use if no other variant is given, e.g. `DEFAULT`
+    pub fn system_check_variant(&self) -> Option<&str> {
+        self.property("systemCheckVariant")
+    }
+}
+
+/// The objects to run the ATC checks on.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::atc::RunRequestBuilder;
+///
+/// RunRequestBuilder::default()
+///     .object("/sap/bc/adt/oo/classes/zcl_demo")
+///     .object("/sap/bc/adt/packages/%24tmp")
+///     .max_verdicts(100u64)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct RunRequest {
+    /// The URIs of the objects to check, e.g. `/sap/bc/adt/oo/classes/zcl_demo`
+    #[builder(setter(each(name = "object", into)))]
+    objects: Vec<String>,
+
+    /// The maximum number of findings to report.
+    #[builder(default = 100)]
+    max_verdicts: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct ObjectSets<'a> {
+    #[serde(rename = "objectSet")]
+    set: ObjectSet<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "atc:run")]
+struct RunRequestBody<'a> {
+    #[serde(rename = "@maximumVerdicts")]
+    max_verdicts: u64,
+
+    #[serde(rename = "objectSets")]
+    object_sets: ObjectSets<'a>,
+}
+
+impl Serialize for RunRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RunRequestBody {
+            max_verdicts: self.max_verdicts,
+            object_sets: ObjectSets {
+                set: ObjectSet::inclusive(&self.objects),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for RunRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("atc".into(), "http://www.sap.com/adt/atc".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+/// An informational entry of a run, e.g. the finding statistics.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcinfo:info")]
+#[readonly::make]
+pub struct RunInfo {
+    /// The type of the info, e.g. `FINDING_STATS`
+    #[serde(rename = "atcinfo:type")]
+    pub kind: String,
+
+    #[serde(rename = "atcinfo:description")]
+    pub description: String,
+}
+
+/// Wraps a collection of [`RunInfo`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "atcworklist:infos")]
+#[readonly::make]
+pub struct RunInfos {
+    #[serde(rename = "atcinfo:info", default)]
+    pub infos: Vec<RunInfo>,
+}
+
+/// The result of an ATC run, the findings are fetched through the worklist.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcworklist:worklistRun")]
+#[readonly::make]
+pub struct WorklistRun {
+    /// The id of the worklist the findings were added to
+    #[serde(rename = "atcworklist:worklistId")]
+    pub worklist_id: String,
+
+    /// The point in time of the run, used to fetch its findings from the worklist
+    #[serde(rename = "atcworklist:worklistTimestamp")]
+    pub timestamp: DateTime<Utc>,
+
+    #[serde(rename = "atcworklist:infos", default)]
+    pub infos: RunInfos,
+}
+
+/// The kinds of quick fixes available for a [`Finding`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcfinding:quickfixes")]
+#[readonly::make]
+pub struct FindingQuickFixes {
+    #[serde(rename = "@atcfinding:manual", default)]
+    pub manual: bool,
+
+    #[serde(rename = "@atcfinding:automatic", default)]
+    pub automatic: bool,
+
+    #[serde(rename = "@atcfinding:pseudo", default)]
+    pub pseudo: bool,
+}
+
+/// A finding of an ATC check, e.g. a missing authority check.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcfinding:finding")]
+#[readonly::make]
+pub struct Finding {
+    /// The URI of the finding itself, used to request an exemption
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The location of the finding, e.g. `/sap/bc/adt/oo/classes/zcl_demo/source/main#start=10,2`
+    #[serde(rename = "@atcfinding:location")]
+    pub location_uri: String,
+
+    /// The priority of the finding, `1` being the most severe
+    #[serde(rename = "@atcfinding:priority")]
+    pub priority: u8,
+
+    #[serde(rename = "@atcfinding:checkId")]
+    pub check_id: String,
+
+    /// The title of the check, e.g. `Extended Program Check (SLIN)`
+    #[serde(rename = "@atcfinding:checkTitle")]
+    pub check_title: String,
+
+    #[serde(rename = "@atcfinding:messageId")]
+    pub message_id: String,
+
+    /// The message of the finding
+    #[serde(rename = "@atcfinding:messageTitle")]
+    pub message_title: String,
+
+    /// The approval state of an exemption for this finding, if one was requested
+    #[serde(rename = "@atcfinding:exemptionApproval")]
+    pub exemption_approval: Option<String>,
+
+    /// The kind of exemption for this finding, if one was requested
+    #[serde(rename = "@atcfinding:exemptionKind")]
+    pub exemption_kind: Option<String>,
+
+    /// Links to the documentation of the check
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    #[serde(rename = "atcfinding:quickfixes")]
+    pub quick_fixes: Option<FindingQuickFixes>,
+}
+
+impl Finding {
+    /// The position in the source code of the finding, if it refers to one.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location_uri.parse().ok()
+    }
+
+    /// Whether an exemption was granted or requested for this finding.
+    pub fn is_exempted(&self) -> bool {
+        self.exemption_approval
+            .as_deref()
+            .is_some_and(|approval| !approval.is_empty())
+    }
+}
+
+/// Wraps a collection of [`Finding`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "atcobject:findings")]
+#[readonly::make]
+pub struct Findings {
+    #[serde(rename = "atcfinding:finding", default)]
+    pub findings: Vec<Finding>,
+}
+
+/// A checked object along with its findings.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcobject:object")]
+#[readonly::make]
+pub struct CheckedObject {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "@adtcore:packageName")]
+    pub package_name: Option<String>,
+
+    /// The user responsible for the object
+    #[serde(rename = "@atcobject:author")]
+    pub author: Option<String>,
+
+    #[serde(rename = "atcobject:findings", default)]
+    pub findings: Findings,
+}
+
+/// Wraps a collection of [`CheckedObject`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "atcworklist:objects")]
+#[readonly::make]
+pub struct CheckedObjects {
+    #[serde(rename = "atcobject:object", default)]
+    pub objects: Vec<CheckedObject>,
+}
+
+/// A worklist, i.e. the findings of the runs of a user.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcworklist:worklist")]
+#[readonly::make]
+pub struct Worklist {
+    #[serde(rename = "@atcworklist:id")]
+    pub id: String,
+
+    #[serde(rename = "@atcworklist:timestamp")]
+    pub timestamp: DateTime<Utc>,
+
+    /// Whether all objects of the object set were checked
+    #[serde(rename = "@atcworklist:objectSetIsComplete", default)]
+    pub object_set_complete: bool,
+
+    #[serde(rename = "atcworklist:objects", default)]
+    pub objects: CheckedObjects,
+}
+
+impl Worklist {
+    /// All findings of all objects.
+    pub fn findings(&self) -> impl Iterator<Item = &Finding> {
+        self.objects
+            .objects
+            .iter()
+            .flat_map(|object| object.findings.findings.iter())
+    }
+
+    /// The findings with the given priority or a more severe one, e.g. `1` and `2`
+    pub fn findings_up_to(&self, priority: u8) -> impl Iterator<Item = &Finding> {
+        self.findings()
+            .filter(move |finding| finding.priority <= priority)
+    }
+}
+
+/// To which findings a requested exemption applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExemptionScope {
+    /// Only the given finding
+    Finding,
+    /// All findings of the same check in the object
+    Object,
+}
+
+impl ExemptionScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Finding => "finding",
+            Self::Object => "object",
+        }
+    }
+}
+
+impl Serialize for ExemptionScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A request for an exemption of a finding.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::atc::{ExemptionRequestBuilder, ExemptionScope};
+///
+/// ExemptionRequestBuilder::default()
+///     .finding_uri("/sap/bc/adt/atc/items/itemid/0242AC11/index/42")
+///     .reason("FPOS")
+///     .justification("The authority check happens in the caller.")
+///     .approver("QUALITY")
+///     .scope(ExemptionScope::Finding)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder, Serialize)]
+#[serde(rename = "atcexmpt:exemptionProposal")]
+#[builder(setter(strip_option))]
+pub struct ExemptionRequest {
+    /// The URI of the finding, see [`Finding::uri`]
+    #[serde(rename = "atcexmpt:finding")]
+    #[builder(setter(into))]
+    finding_uri: String,
+
+    #[serde(rename = "atcexmpt:restriction")]
+    #[builder(default = ExemptionScope::Finding)]
+    scope: ExemptionScope,
+
+    /// The user who has to approve the exemption
+    #[serde(rename = "atcexmpt:approver")]
+    #[builder(setter(into))]
+    approver: String,
+
+    /// The id of the reason, see [`ExemptionReason`], e.g. `FPOS`
+    #[serde(rename = "atcexmpt:reason")]
+    #[builder(setter(into))]
+    reason: String,
+
+    #[serde(rename = "atcexmpt:justification")]
+    #[builder(setter(into), default)]
+    justification: String,
+}
+
+impl IntoXmlRoot for ExemptionRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![(
+            "atcexmpt".into(),
+            "http://www.sap.com/adt/atc/exemption".into(),
+        )]
+    }
+}
+
+/// The answer to an [`ExemptionRequest`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcexmpt:status")]
+#[readonly::make]
+pub struct ExemptionStatus {
+    #[serde(rename = "atcexmpt:message")]
+    pub message: String,
+
+    #[serde(rename = "atcexmpt:type")]
+    pub severity: Severity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_customizing() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atccust:customizing xmlns:atccust="http://www.sap.com/adt/atc/customizing">
+                <properties>
+                    <property name="systemCheckVariant" value="DEFAULT"/>
+                    <property name="exemptionsEnabled" value="true"/>
+                </properties>
+                <exemption>
+                    <reasons>
+                        <reason id="FPOS" title="False Positive" justificationMandatory="true"/>
+                        <reason id="OTHR" title="Other" justificationMandatory="true"/>
+                    </reasons>
+                </exemption>
+            </atccust:customizing>"#;
+
+        let result: Customizing = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.system_check_variant(), Some("DEFAULT"));
+        assert_eq!(result.exemption.reasons.reasons.len(), 2);
+    }
+
+    #[test]
+    fn serialize_run_request() {
+        let request = RunRequestBuilder::default()
+            .object("/sap/bc/adt/oo/classes/zcl_demo")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <atc:run xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:atc=\"http://www.sap.com/adt/atc\" maximumVerdicts=\"100\">\
+                <objectSets>\
+                    <objectSet kind=\"inclusive\">\
+                        <adtcore:objectReferences>\
+                            <adtcore:objectReference adtcore:uri=\"/sap/bc/adt/oo/classes/zcl_demo\" />\
+                        </adtcore:objectReferences>\
+                    </objectSet>\
+                </objectSets>\
+            </atc:run>"
+        );
+    }
+
+    #[test]
+    fn deserialize_worklist_run() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcworklist:worklistRun xmlns:atcworklist="http://www.sap.com/adt/atc/worklist">
+                <atcworklist:worklistId>0242AC1100021EDF</atcworklist:worklistId>
+                <atcworklist:worklistTimestamp>2025-09-01T10:15:30Z</atcworklist:worklistTimestamp>
+                <atcworklist:infos>
+                    <atcinfo:info xmlns:atcinfo="http://www.sap.com/adt/atc/info">
+                        <atcinfo:type>FINDING_STATS</atcinfo:type>
+                        <atcinfo:description>0,1,1</atcinfo:description>
+                    </atcinfo:info>
+                </atcworklist:infos>
+            </atcworklist:worklistRun>"#;
+
+        let result: WorklistRun = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.worklist_id, "0242AC1100021EDF");
+        assert_eq!(result.infos.infos[0].kind, "FINDING_STATS");
+    }
+
+    #[test]
+    fn deserialize_worklist() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcworklist:worklist atcworklist:id="0242AC1100021EDF" atcworklist:timestamp="2025-09-01T10:15:30Z" atcworklist:usedObjectSet="99999999999999999999999999999999" atcworklist:objectSetIsComplete="true" xmlns:atcworklist="http://www.sap.com/adt/atc/worklist">
+                <atcworklist:objects>
+                    <atcobject:object adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO" adtcore:packageName="$TMP" atcobject:author="DEVELOPER" xmlns:atcobject="http://www.sap.com/adt/atc/object" xmlns:adtcore="http://www.sap.com/adt/core">
+                        <atcobject:findings>
+                            <atcfinding:finding adtcore:uri="/sap/bc/adt/atc/items/itemid/0242AC11/index/42" atcfinding:location="/sap/bc/adt/oo/classes/zcl_demo/source/main#start=10,2" atcfinding:processor="" atcfinding:lastChangedBy="" atcfinding:priority="1" atcfinding:checkId="0242AC1100021EE0" atcfinding:checkTitle="Security Checks" atcfinding:messageId="AUTH" atcfinding:messageTitle="Missing authority check" atcfinding:exemptionApproval="" atcfinding:exemptionKind="" xmlns:atcfinding="http://www.sap.com/adt/atc/finding">
+                                <atom:link href="/sap/bc/adt/documentation/atc/documents/itemid/0242AC11/index/42" rel="http://www.sap.com/adt/relations/documentation" type="text/html" xmlns:atom="http://www.w3.org/2005/Atom"/>
+                                <atcfinding:quickfixes atcfinding:manual="false" atcfinding:automatic="false" atcfinding:pseudo="false"/>
+                            </atcfinding:finding>
+                            <atcfinding:finding adtcore:uri="/sap/bc/adt/atc/items/itemid/0242AC11/index/43" atcfinding:location="/sap/bc/adt/oo/classes/zcl_demo/source/main#start=20,4" atcfinding:priority="3" atcfinding:checkId="0242AC1100021EE1" atcfinding:checkTitle="Extended Program Check (SLIN)" atcfinding:messageId="0815" atcfinding:messageTitle="Variable is not used" xmlns:atcfinding="http://www.sap.com/adt/atc/finding"/>
+                        </atcobject:findings>
+                    </atcobject:object>
+                </atcworklist:objects>
+            </atcworklist:worklist>"#;
+
+        let result: Worklist = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.findings().count(), 2);
+        assert_eq!(result.findings_up_to(2).count(), 1);
+
+        let finding = result.findings().next().unwrap();
+        assert_eq!(finding.check_title, "Security Checks");
+        assert_eq!(finding.location().map(|l| l.start.line), Some(10));
+        assert!(!finding.is_exempted());
+    }
+
+    #[test]
+    fn serialize_exemption_request() {
+        let request = ExemptionRequestBuilder::default()
+            .finding_uri("/sap/bc/adt/atc/items/itemid/0242AC11/index/42")
+            .reason("FPOS")
+            .justification("Checked by the caller.")
+            .approver("QUALITY")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <atcexmpt:exemptionProposal xmlns:atcexmpt=\"http://www.sap.com/adt/atc/exemption\">\
+                <atcexmpt:finding>/sap/bc/adt/atc/items/itemid/0242AC11/index/42</atcexmpt:finding>\
+                <atcexmpt:restriction>finding</atcexmpt:restriction>\
+                <atcexmpt:approver>QUALITY</atcexmpt:approver>\
+                <atcexmpt:reason>FPOS</atcexmpt:reason>\
+                <atcexmpt:justification>Checked by the caller.</atcexmpt:justification>\
+            </atcexmpt:exemptionProposal>"
+        );
+    }
+
+    #[test]
+    fn deserialize_exemption_status() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcexmpt:status xmlns:atcexmpt="http://www.sap.com/adt/atc/exemption">
+                <atcexmpt:message>Exemption was requested</atcexmpt:message>
+                <atcexmpt:type>S</atcexmpt:type>
+            </atcexmpt:status>"#;
+
+        let result: ExemptionStatus = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.severity, Severity::Success);
+    }
+}
@@ -0,0 +1,197 @@
+/// CDS Sources (DDLS, DDLX, DCLS) - http://www.sap.com/adt/ddic/ddlsources
+///
+/// Data definitions, metadata extensions (http://www.sap.com/adt/ddic/ddlxsources) and
+/// access controls (http://www.sap.com/adt/acm/dclsources) share the same structure.
+use crate::models::{adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Represents a CDS source, i.e. a data definition, metadata extension or access control.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct CdsSourceMetadata {
+    /// The name of the source, e.g. `ZI_TRAVEL`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the source, e.g. `DDLS/DF`, `DDLX/EX` or `DCLS/DL`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the source was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the source descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the source was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who last changed this source
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The user who created this source
+    #[serde(rename = "@adtcore:createdBy")]
+    pub created_by: Option<String>,
+
+    /// The description of the source
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The language of the source, e.g. `EN`
+    #[serde(rename = "@adtcore:language")]
+    pub language: String,
+
+    /// The relative uri to fetch the source code
+    #[serde(rename = "@abapsource:sourceUri", default)]
+    pub source_uri: String,
+
+    /// The user who is responsible for this source
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the source
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The system this source belongs to
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: String,
+
+    /// The ABAP Version of the source
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Relative URLs to related operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the package the source belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+}
+
+impl CdsSourceMetadata {
+    /// The etag of the plain text source, if provided.
+    pub fn source_etag(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.kind.as_deref() == Some("text/plain"))
+            .and_then(|link| link.etag.as_deref())
+    }
+}
+
+/// An entity of the dependency graph, e.g. a CDS view or a database table.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dependencies:node")]
+#[readonly::make]
+pub struct GraphNode {
+    /// The id of the node within the graph, referenced by the [`GraphEdge`]s
+    #[serde(rename = "@dependencies:id")]
+    pub id: String,
+
+    /// The name of the entity, e.g. `ZI_TRAVEL` or `SFLIGHT`
+    #[serde(rename = "@dependencies:name")]
+    pub name: String,
+
+    /// The type of the entity, e.g. `DDLS/DF` or `TABL/DT`
+    #[serde(rename = "@dependencies:type", default)]
+    pub object_type: String,
+
+    /// The URI of the entity, if it is a repository object.
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: Option<String>,
+
+    /// The activation state of the entity, e.g. `active`
+    #[serde(rename = "@dependencies:activationState")]
+    pub activation_state: Option<String>,
+}
+
+/// A usage of one entity by another, e.g. a view selecting from a table.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dependencies:edge")]
+#[readonly::make]
+pub struct GraphEdge {
+    /// The id of the using node.
+    #[serde(rename = "@dependencies:source")]
+    pub source: String,
+
+    /// The id of the used node.
+    #[serde(rename = "@dependencies:target")]
+    pub target: String,
+
+    /// How the entity is used, e.g. `select_from`, `association` or `join`
+    #[serde(rename = "@dependencies:relation", default)]
+    pub relation: String,
+}
+
+/// The entities a data definition is built upon, e.g. to analyse the impact of a change.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dependencies:graphData")]
+#[readonly::make]
+pub struct DependencyGraph {
+    #[serde(rename = "dependencies:node", default)]
+    pub nodes: Vec<GraphNode>,
+
+    #[serde(rename = "dependencies:edge", default)]
+    pub edges: Vec<GraphEdge>,
+}
+
+impl DependencyGraph {
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the nodes the given node directly depends on.
+    pub fn dependencies_of<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a GraphNode> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.source == id)
+            .filter_map(|edge| self.node(&edge.target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_ddl_source_metadata() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ddl:ddlSource abapsource:sourceUri="source/main" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="5" adtcore:name="ZI_TRAVEL" adtcore:type="DDLS/DF" adtcore:changedAt="2025-09-12T08:15:31Z" adtcore:version="active" adtcore:createdAt="2025-09-01T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:createdBy="DEVELOPER" adtcore:description="Travel" adtcore:language="EN" xmlns:ddl="http://www.sap.com/adt/ddic/ddlsources" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main/versions" rel="http://www.sap.com/adt/relations/versions"/>
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509120815310011"/>
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+            </ddl:ddlSource>"#;
+
+        let result: CdsSourceMetadata = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.name, "ZI_TRAVEL");
+        assert_eq!(result.object_type, "DDLS/DF");
+        assert_eq!(result.package.name, "$TMP");
+        assert_eq!(result.source_etag(), Some("202509120815310011"));
+    }
+
+    #[test]
+    fn deserialize_dependency_graph() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <dependencies:graphData xmlns:dependencies="http://www.sap.com/adt/ddic/ddl/dependencies" xmlns:adtcore="http://www.sap.com/adt/core">
+                <dependencies:node dependencies:id="1" dependencies:name="ZC_TRAVEL" dependencies:type="DDLS/DF" adtcore:uri="/sap/bc/adt/ddic/ddl/sources/zc_travel" dependencies:activationState="active"/>
+                <dependencies:node dependencies:id="2" dependencies:name="ZI_TRAVEL" dependencies:type="DDLS/DF" adtcore:uri="/sap/bc/adt/ddic/ddl/sources/zi_travel"/>
+                <dependencies:node dependencies:id="3" dependencies:name="ZTRAVEL" dependencies:type="TABL/DT"/>
+                <dependencies:edge dependencies:source="1" dependencies:target="2" dependencies:relation="select_from"/>
+                <dependencies:edge dependencies:source="2" dependencies:target="3" dependencies:relation="select_from"/>
+            </dependencies:graphData>"#;
+
+        let result: DependencyGraph = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.nodes.len(), 3);
+
+        let dependencies: Vec<_> = result.dependencies_of("2").collect();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].name, "ZTRAVEL");
+        assert!(dependencies[0].uri.is_none());
+    }
+}
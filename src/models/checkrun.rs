@@ -1,5 +1,12 @@
+/// Check Runs - http://www.sap.com/adt/checkrun
+use crate::models::{
+    abapsource::{Severity, SourceLocation},
+    serialize::IntoXmlRoot,
+};
+use base64::{Engine, engine::general_purpose};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// A `Reporter` that can be used to check objects.
 ///
@@ -75,17 +82,32 @@ pub struct Message {
     #[serde(rename = "@chkrun:uri")]
     pub location_uri: String,
 
-    /// The kind of message, e.g `W` for **Warning**, or `E` for **Error**.
+    /// The severity of the message, e.g `W` for **Warning**, or `E` for **Error**.
     #[serde(rename = "@chkrun:type")]
-    pub kind: String,
+    pub severity: Severity,
 
     /// An informational text about what the "problem" or reason of the message is.
     #[serde(rename = "@chkrun:shortText")]
     pub text: String,
 
-    /// Optional: a quickfix to the problem at hand.
-    #[serde(rename = "atom:link")]
-    pub quick_fix: Option<QuickFix>,
+    /// References to the quick fixes of the problem at hand, if there are any.
+    #[serde(rename = "atom:link", default)]
+    pub quick_fixes: Vec<QuickFix>,
+}
+
+impl Message {
+    /// The range in the source code the message refers to, if the URI contains one.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location_uri.parse().ok()
+    }
+
+    /// Whether quick fixes can be proposed for the message.
+    ///
+    /// The proposals are evaluated at the [`Message::location`], see
+    /// [`crate::api::quickfixes::QuickFixProposals`]
+    pub fn has_quick_fix(&self) -> bool {
+        self.quick_fixes.iter().any(QuickFix::is_quick_fix)
+    }
 }
 
 /// Wraps a collection of [`Message`]s.
@@ -99,16 +121,28 @@ pub struct MessageList {
     pub messages: Vec<Message>,
 }
 
-/// A quick fix to an error or warning in the code.
-///
-/// Seems to refer to some kind e.g `art.syntax2G(`.
+/// A reference to the quick fixes of an error or warning in the code.
 ///
-/// TODO: Find out how this works.
+/// The reference only tells that fixes exist, e.g. `art.syntax:G(2` for the syntax check,
+/// the actual proposals are evaluated at the location of the message.
 #[derive(Debug, Deserialize)]
+#[serde(rename = "atom:link")]
 #[readonly::make]
 pub struct QuickFix {
+    /// The check that provides the fixes along with the message code, e.g. `art.syntax:G(2`
     #[serde(rename = "@href")]
-    kind: String,
+    pub href: String,
+
+    /// The relation of the link, `http://www.sap.com/adt/categories/quickfixes` for quick fixes.
+    #[serde(rename = "@rel")]
+    pub rel: Option<String>,
+}
+
+impl QuickFix {
+    /// Whether the link actually refers to quick fixes.
+    pub fn is_quick_fix(&self) -> bool {
+        self.rel.as_deref() == Some("http://www.sap.com/adt/categories/quickfixes")
+    }
 }
 
 /// An object to be checked by the check runner.
@@ -124,6 +158,20 @@ pub struct QuickFix {
 ///     .version("active")
 ///     .build();
 /// ```
+///
+/// Unsaved content is checked by passing it along as [`Artifact`]:
+/// ```
+/// use adt_query::models::checkrun::{Artifact, ObjectBuilder};
+///
+/// ObjectBuilder::default()
+///     .object_uri("/sap/bc/adt/programs/programs/z_my_program")
+///     .version("inactive")
+///     .artifact(Artifact::source(
+///         "/sap/bc/adt/programs/programs/z_my_program/source/main",
+///         "REPORT z_my_program.\nWRITE 'Hello'.",
+///     ))
+///     .build();
+/// ```
 #[derive(Builder, Debug, Serialize, Clone)]
 #[serde(rename = "chkrun:checkObject")]
 pub struct Object {
@@ -134,6 +182,58 @@ pub struct Object {
     #[serde(rename = "@chkrun:version")]
     #[builder(setter(into))]
     version: String,
+
+    /// Content to check instead of the saved version, see [`Artifact`].
+    #[serde(rename = "chkrun:artifacts", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(custom), default)]
+    artifacts: Option<Artifacts>,
+}
+
+impl ObjectBuilder {
+    /// Adds content to be checked in place of the saved source at the artifact URI.
+    pub fn artifact(&mut self, artifact: Artifact) -> &mut Self {
+        self.artifacts
+            .get_or_insert(None)
+            .get_or_insert_with(Artifacts::default)
+            .artifacts
+            .push(artifact);
+        self
+    }
+}
+
+/// Unsaved content of an object, e.g. the source code in an editor that was not saved yet.
+///
+/// The content is transferred base64 encoded, like Eclipse does for live syntax checks.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "chkrun:artifact")]
+pub struct Artifact {
+    #[serde(rename = "@chkrun:contentType")]
+    content_type: String,
+
+    #[serde(rename = "@chkrun:uri")]
+    uri: String,
+
+    #[serde(rename = "chkrun:content")]
+    content: String,
+}
+
+impl Artifact {
+    /// Plain text source code at the given URI, e.g. `/sap/bc/adt/oo/classes/zcl_demo/source/main`
+    pub fn source<U: Into<String>>(uri: U, source: &str) -> Self {
+        Self {
+            content_type: String::from("text/plain; charset=utf-8"),
+            uri: uri.into(),
+            content: general_purpose::STANDARD.encode(source),
+        }
+    }
+}
+
+/// Wraps a collection of [`Artifact`]s
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename = "chkrun:artifacts")]
+struct Artifacts {
+    #[serde(rename = "chkrun:artifact")]
+    artifacts: Vec<Artifact>,
 }
 
 /// Wraps a collection of [`Object`]
@@ -147,9 +247,19 @@ pub struct ObjectList {
     objects: Vec<Object>,
 }
 
+impl IntoXmlRoot for ObjectList {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("chkrun".into(), "http://www.sap.com/adt/checkrun".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::abapsource::Position;
 
     #[test]
     fn deserialize_checkrun_reporters() {
@@ -191,6 +301,7 @@ mod tests {
             objects: vec![Object {
                 object_uri: String::from("/sap/bc/adt/programs/programs/zwegwerf1"),
                 version: String::from("active"),
+                artifacts: None,
             }],
         };
 
@@ -232,5 +343,48 @@ mod tests {
                 .map(|m| m.messages.len()),
             Some(8)
         );
+
+        let messages = &result.reports[0].messages.as_ref().unwrap().messages;
+        assert_eq!(messages[0].severity, Severity::Error);
+        assert_eq!(messages[4].severity, Severity::Warning);
+        assert_eq!(
+            messages[0].location().map(|location| location.start),
+            Some(Position::new(193, 19))
+        );
+        assert!(messages[0].has_quick_fix());
+        assert_eq!(messages[0].quick_fixes[0].href, "art.syntax:G(2");
+        assert!(!messages[4].has_quick_fix());
+    }
+
+    #[test]
+    fn serialize_check_object_with_artifact() {
+        let objects = ObjectListBuilder::default()
+            .object(
+                ObjectBuilder::default()
+                    .object_uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                    .version("inactive")
+                    .artifact(Artifact::source(
+                        "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+                        "REPORT zwegwerf1.",
+                    ))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            objects.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <chkrun:checkObjectList xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:chkrun=\"http://www.sap.com/adt/checkrun\">\
+                <chkrun:checkObject adtcore:uri=\"/sap/bc/adt/programs/programs/zwegwerf1\" chkrun:version=\"inactive\">\
+                    <chkrun:artifacts>\
+                        <chkrun:artifact chkrun:contentType=\"text/plain; charset=utf-8\" chkrun:uri=\"/sap/bc/adt/programs/programs/zwegwerf1/source/main\">\
+                            <chkrun:content>UkVQT1JUIHp3ZWd3ZXJmMS4=</chkrun:content>\
+                        </chkrun:artifact>\
+                    </chkrun:artifacts>\
+                </chkrun:checkObject>\
+            </chkrun:checkObjectList>"
+        );
     }
 }
@@ -0,0 +1,281 @@
+/// Classes (CLAS) - http://www.sap.com/adt/oo/classes
+use crate::models::{abapsource, adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// The source code includes a global class is made up of.
+///
+/// In the editor, `Definitions` are shown as "Class-relevant Local Types" and
+/// `Implementations` as "Local Types".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClassInclude {
+    /// The global class definition and implementation (`CP`)
+    #[default]
+    Main,
+    /// Local type definitions that are relevant for the global class (`CCDEF`)
+    Definitions,
+    /// Local types and local class implementations (`CCIMP`)
+    Implementations,
+    /// Macro definitions (`CCMAC`)
+    Macros,
+    /// Local test classes (`CCAU`)
+    TestClasses,
+}
+
+impl ClassInclude {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::Definitions => "definitions",
+            Self::Implementations => "implementations",
+            Self::Macros => "macros",
+            Self::TestClasses => "testclasses",
+        }
+    }
+
+    /// The URI of the include source code relative to the class, e.g. `includes/macros`
+    pub fn source_uri(&self) -> String {
+        match self {
+            Self::Main => "source/main".into(),
+            include => format!("includes/{}", include.as_str()),
+        }
+    }
+}
+
+impl FromStr for ClassInclude {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "main" => Ok(Self::Main),
+            "definitions" => Ok(Self::Definitions),
+            "implementations" => Ok(Self::Implementations),
+            "macros" => Ok(Self::Macros),
+            "testclasses" => Ok(Self::TestClasses),
+            other => Err(format!("unknown class include '{other}'")),
+        }
+    }
+}
+
+/// Represents a global ABAP class
+#[derive(Debug, Deserialize)]
+#[serde(rename = "class:abapClass")]
+#[readonly::make]
+pub struct ClassMetadata {
+    /// The name of the class
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the class, should be `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the class was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the class descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the class was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who last changed this class
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The user who created this class
+    #[serde(rename = "@adtcore:createdBy")]
+    pub created_by: Option<String>,
+
+    /// The description of the class
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The character limit of the class description
+    #[serde(rename = "@adtcore:descriptionTextLimit")]
+    pub description_text_limit: Option<i32>,
+
+    /// The language of the class, e.g. `EN`
+    #[serde(rename = "@adtcore:language")]
+    pub language: String,
+
+    /// Whether the class is final, i.e. cannot be inherited from
+    #[serde(rename = "@class:final")]
+    pub is_final: bool,
+
+    /// Whether the class is abstract, i.e. cannot be instantiated
+    #[serde(rename = "@class:abstract")]
+    pub is_abstract: bool,
+
+    /// The instantiation visibility of the class, e.g. `public`
+    #[serde(rename = "@class:visibility")]
+    pub visibility: String,
+
+    /// The category of the class, e.g. `generalObjectType` or `exceptionClass`
+    #[serde(rename = "@class:category")]
+    pub category: String,
+
+    /// Whether the class contains local test classes
+    #[serde(rename = "@class:hasTests", default)]
+    pub has_tests: bool,
+
+    /// Whether the class supports shared memory
+    #[serde(rename = "@class:sharedMemoryEnabled", default)]
+    pub shared_memory_enabled: bool,
+
+    /// The relative uri to fetch the class source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: Option<String>,
+
+    /// Whether the class supports fixed point arithmetic
+    #[serde(rename = "@abapsource:fixPointArithmetic")]
+    pub fix_point_arithmetic: bool,
+
+    /// Whether unicode checks are active for this class
+    #[serde(rename = "@abapsource:activeUnicodeCheck")]
+    pub unicode_check_active: bool,
+
+    /// The user who is responsible for this class
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the class
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The system this class belongs to
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: String,
+
+    /// The ABAP Version of the class
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Relative URLs to related class Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the package the class belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Syntax Configuration of the class
+    #[serde(rename = "abapsource:syntaxConfiguration")]
+    pub syntax_configuration: abapsource::SyntaxConfiguration,
+
+    /// The source code includes of the class, see [`ClassInclude`]
+    #[serde(rename = "class:include", default)]
+    pub includes: Vec<ClassIncludeMetadata>,
+
+    /// Reference to the super class, if the class inherits from one.
+    #[serde(rename = "class:superClassRef")]
+    pub super_class: Option<adtcore::ObjectReference>,
+}
+
+impl ClassMetadata {
+    /// Returns the metadata of the given include, if the class has it.
+    pub fn include(&self, include: ClassInclude) -> Option<&ClassIncludeMetadata> {
+        self.includes.iter().find(|i| i.kind() == Some(include))
+    }
+}
+
+/// Metadata of a single source code include of a class.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "class:include")]
+#[readonly::make]
+pub struct ClassIncludeMetadata {
+    /// The type of the include as provided by the system, e.g. `testclasses` - see
+    /// [`ClassIncludeMetadata::kind`]
+    #[serde(rename = "@class:includeType")]
+    pub include_type: String,
+
+    /// The relative uri to fetch the include source code, e.g. `includes/testclasses`
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// The version of the include, e.g `active` or `inactive`
+    #[serde(rename = "@adtcore:version")]
+    pub version: Option<String>,
+
+    /// The datetime that the include was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: Option<DateTime<Utc>>,
+
+    /// The user who last changed the include
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: Option<String>,
+
+    /// Relative URLs to the source of the include, these carry the current etag.
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl ClassIncludeMetadata {
+    /// The type of the include, `None` for includes unknown to this crate.
+    pub fn kind(&self) -> Option<ClassInclude> {
+        self.include_type.parse().ok()
+    }
+
+    /// The etag of the plain text source of the include, if provided.
+    pub fn etag(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.kind.as_deref() == Some("text/plain"))
+            .and_then(|link| link.etag.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_abap_class_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <class:abapClass class:final="true" class:abstract="false" class:visibility="public" class:category="generalObjectType" class:hasTests="true" class:sharedMemoryEnabled="false" abapsource:sourceUri="source/main" abapsource:fixPointArithmetic="true" abapsource:activeUnicodeCheck="true" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="X" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/OC" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:createdAt="2023-03-08T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:createdBy="DEVELOPER" adtcore:description="Demo" adtcore:descriptionTextLimit="60" adtcore:language="EN" xmlns:class="http://www.sap.com/adt/oo/classes" xmlns:abapoo="http://www.sap.com/adt/oo" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main/versions" rel="http://www.sap.com/adt/relations/versions"/>
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440011"/>
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                <abapsource:syntaxConfiguration>
+                    <abapsource:language>
+                        <abapsource:version>X</abapsource:version>
+                        <abapsource:description>Standard ABAP</abapsource:description>
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="/sap/bc/adt/abapsource/parsers/rnd/grammar" rel="http://www.sap.com/adt/relations/abapsource/parser" type="text/plain" title="Standard ABAP" etag="757"/>
+                    </abapsource:language>
+                </abapsource:syntaxConfiguration>
+                <class:include class:includeType="definitions" abapsource:sourceUri="includes/definitions" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/I" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:changedBy="DEVELOPER">
+                    <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="./zcl_demo/includes/definitions" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440012"/>
+                </class:include>
+                <class:include class:includeType="testclasses" abapsource:sourceUri="includes/testclasses" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/I" adtcore:version="active">
+                    <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="./zcl_demo/includes/testclasses" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440013"/>
+                </class:include>
+                <class:include class:includeType="localtypes" abapsource:sourceUri="includes/localtypes" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/I" adtcore:version="active"/>
+                <class:include class:includeType="main" abapsource:sourceUri="source/main" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/I" adtcore:version="active"/>
+                <class:superClassRef adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo_base" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO_BASE"/>
+            </class:abapClass>"#;
+
+        let result: ClassMetadata = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.is_final);
+        assert_eq!(result.includes.len(), 4);
+        assert!(result.includes[2].kind().is_none());
+        assert_eq!(
+            result.include(ClassInclude::TestClasses).unwrap().etag(),
+            Some("202508302149440013")
+        );
+        assert!(result.include(ClassInclude::Macros).is_none());
+        assert_eq!(result.super_class.unwrap().name, "ZCL_DEMO_BASE");
+    }
+
+    #[test]
+    fn include_source_uris() {
+        assert_eq!(ClassInclude::Main.source_uri(), "source/main");
+        assert_eq!(
+            ClassInclude::Implementations.source_uri(),
+            "includes/implementations"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+/// Code Completion - http://www.sap.com/adt/abapsource
+///
+/// The proposals are provided as ASX data, the element info uses the abapsource namespace.
+use serde::{Deserialize, Deserializer};
+
+use crate::models::atom;
+
+/// The completion engine reports flags as `1`, some of them as `X`.
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Ok(matches!(s.as_str(), "1" | "X"))
+}
+
+/// The kind of a proposed element, reported by the completion engine as a numeric code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalKind {
+    /// ABAP keywords, e.g. `ENDIF` - the meta entries carry this kind as well (`0`)
+    Keyword,
+    /// Data objects, e.g. variables, constants or parameters (`1`)
+    DataObject,
+    /// A kind unknown to this crate, with the code reported by the engine.
+    Unknown(u32),
+}
+
+impl From<u32> for ProposalKind {
+    fn from(code: u32) -> Self {
+        match code {
+            0 => Self::Keyword,
+            1 => Self::DataObject,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProposalKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(u32::deserialize(deserializer)?.into())
+    }
+}
+
+/// A proposal of the completion engine for the cursor position.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SCC_COMPLETION")]
+#[readonly::make]
+pub struct Proposal {
+    /// The kind of the proposed element, see [`ProposalKind`]
+    #[serde(rename = "KIND")]
+    pub kind: ProposalKind,
+
+    /// The identifier of the proposed element, e.g. `lv_counter` or `ENDIF`
+    #[serde(rename = "IDENTIFIER")]
+    pub identifier: String,
+
+    /// The icon ADT shows for the proposal.
+    #[serde(rename = "ICON", default)]
+    pub icon: String,
+
+    /// The number of characters before the cursor the proposal replaces.
+    #[serde(rename = "PREFIXLENGTH", default)]
+    pub prefix_length: u32,
+
+    /// Whether the element info of the proposal is available, see [`ElementInfo`]
+    #[serde(
+        rename = "QUICKINFO_EVENT",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub has_element_info: bool,
+
+    /// Whether a full pattern can be inserted for the proposal, e.g. a method call - see
+    /// [`crate::api::abapsource::insertion_text`]
+    #[serde(
+        rename = "INSERT_EVENT",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub has_insertion: bool,
+
+    /// Whether the entry only carries information about the proposals, e.g. their completeness.
+    #[serde(rename = "IS_META", deserialize_with = "deserialize_flag", default)]
+    pub is_meta: bool,
+
+    /// Whether the element is inherited, e.g. a method of a super class.
+    #[serde(
+        rename = "IS_INHERITED",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub is_inherited: bool,
+
+    /// The role of the element at the cursor position as numeric code of the engine, e.g. `21`
+    #[serde(rename = "ROLE", default)]
+    pub role: String,
+
+    /// Where the element is declared relative to the cursor as numeric code of the engine,
+    /// e.g. `2` for the current procedure.
+    #[serde(rename = "LOCATION", default)]
+    pub location: String,
+
+    /// How well the proposal matches the prefix before the cursor, higher grades rank first.
+    #[serde(rename = "GRADE", default)]
+    pub grade: String,
+
+    /// The visibility of the element, e.g. for components of classes.
+    #[serde(rename = "VISIBILITY", default)]
+    pub visibility: String,
+}
+
+/// The proposals of the completion engine for the cursor position.
+///
+/// Content Type Version `com.sap.adt.codecompletion.proposals`
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "DATA")]
+#[readonly::make]
+pub struct Proposals {
+    #[serde(rename = "SCC_COMPLETION", default)]
+    pub entries: Vec<Proposal>,
+}
+
+impl Proposals {
+    /// Returns the actual proposals, i.e. without the meta entries.
+    pub fn proposals(&self) -> impl Iterator<Item = &Proposal> {
+        self.entries.iter().filter(|entry| !entry.is_meta)
+    }
+}
+
+/// A property of an element, e.g. its visibility.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "abapsource:entry")]
+#[readonly::make]
+pub struct Property {
+    #[serde(rename = "@abapsource:key")]
+    pub key: String,
+
+    #[serde(rename = "#text", default)]
+    pub value: String,
+}
+
+/// Wraps the properties of an element.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct Properties {
+    #[serde(rename = "abapsource:entry", default)]
+    pub entries: Vec<Property>,
+}
+
+/// Wraps the components of an element, e.g. the methods of a class.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct ElementInfos {
+    #[serde(rename = "abapsource:elementInfo", default)]
+    pub elements: Vec<ElementInfo>,
+}
+
+/// Information about the element at the cursor position, e.g. its documentation.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "abapsource:elementInfo")]
+#[readonly::make]
+pub struct ElementInfo {
+    /// The name of the element, e.g. `CL_ABAP_TYPEDESCR`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The type of the element, e.g. `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: Option<String>,
+
+    /// The documentation of the element as HTML.
+    #[serde(rename = "abapsource:documentation")]
+    pub documentation: Option<String>,
+
+    /// The properties of the element.
+    #[serde(rename = "abapsource:properties", default)]
+    pub properties: Properties,
+
+    /// The components of the element, e.g. the methods of a class.
+    #[serde(rename = "abapsource:elementInfos", default)]
+    pub components: ElementInfos,
+
+    /// Links to the definition of the element.
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl ElementInfo {
+    /// Returns the value of the property with the given key.
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::asx::AsxData;
+
+    #[test]
+    fn deserialize_proposals() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <SCC_COMPLETION>
+                            <KIND>0</KIND>
+                            <IDENTIFIER>@end</IDENTIFIER>
+                            <ICON>0</ICON>
+                            <SUBICON>0</SUBICON>
+                            <BOLD>0</BOLD>
+                            <COLOR>0</COLOR>
+                            <QUICKINFO_EVENT>0</QUICKINFO_EVENT>
+                            <INSERT_EVENT>0</INSERT_EVENT>
+                            <IS_META>1</IS_META>
+                            <PREFIXLENGTH>0</PREFIXLENGTH>
+                            <ROLE>0</ROLE>
+                            <LOCATION>0</LOCATION>
+                            <GRADE>0</GRADE>
+                            <VISIBILITY>0</VISIBILITY>
+                            <IS_INHERITED>0</IS_INHERITED>
+                            <PROP1>0</PROP1>
+                            <PROP2>0</PROP2>
+                            <PROP3>0</PROP3>
+                            <SYNTCNTXT>0</SYNTCNTXT>
+                        </SCC_COMPLETION>
+                        <SCC_COMPLETION>
+                            <KIND>1</KIND>
+                            <IDENTIFIER>lv_counter</IDENTIFIER>
+                            <ICON>7</ICON>
+                            <SUBICON>0</SUBICON>
+                            <BOLD>0</BOLD>
+                            <COLOR>0</COLOR>
+                            <QUICKINFO_EVENT>1</QUICKINFO_EVENT>
+                            <INSERT_EVENT>0</INSERT_EVENT>
+                            <IS_META>0</IS_META>
+                            <PREFIXLENGTH>4</PREFIXLENGTH>
+                            <ROLE>21</ROLE>
+                            <LOCATION>2</LOCATION>
+                            <GRADE>1</GRADE>
+                            <VISIBILITY>0</VISIBILITY>
+                            <IS_INHERITED>0</IS_INHERITED>
+                            <PROP1>0</PROP1>
+                            <PROP2>0</PROP2>
+                            <PROP3>0</PROP3>
+                            <SYNTCNTXT>0</SYNTCNTXT>
+                        </SCC_COMPLETION>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#;
+
+        let result: AsxData<Proposals> = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.entries.len(), 2);
+
+        assert_eq!(result.entries[0].kind, ProposalKind::Keyword);
+
+        let proposal = result.proposals().next().unwrap();
+        assert_eq!(proposal.kind, ProposalKind::DataObject);
+        assert_eq!(proposal.identifier, "lv_counter");
+        assert_eq!(proposal.prefix_length, 4);
+        assert!(proposal.has_element_info);
+        assert!(!proposal.has_insertion);
+    }
+
+    #[test]
+    fn unknown_kinds_are_kept() {
+        assert_eq!(ProposalKind::from(42), ProposalKind::Unknown(42));
+    }
+
+    #[test]
+    fn deserialize_element_info() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <abapsource:elementInfo xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core" xmlns:atom="http://www.w3.org/2005/Atom" adtcore:name="ZCL_DEMO" adtcore:type="CLAS/OC">
+                <abapsource:documentation>&lt;p&gt;Demo class&lt;/p&gt;</abapsource:documentation>
+                <abapsource:properties>
+                    <abapsource:entry abapsource:key="visibility">public</abapsource:entry>
+                </abapsource:properties>
+                <abapsource:elementInfos>
+                    <abapsource:elementInfo adtcore:name="RUN" adtcore:type="CLAS/OM"/>
+                </abapsource:elementInfos>
+                <atom:link href="/sap/bc/adt/oo/classes/zcl_demo" rel="http://www.sap.com/adt/relations/source/definitionIdentifier"/>
+            </abapsource:elementInfo>"#;
+
+        let result: ElementInfo = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.documentation.as_deref(), Some("<p>Demo class</p>"));
+        assert_eq!(result.property("visibility"), Some("public"));
+        assert_eq!(result.components.elements[0].name, "RUN");
+        assert_eq!(result.links.len(), 1);
+    }
+}
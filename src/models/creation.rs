@@ -0,0 +1,313 @@
+/// Request bodies to create new development objects.
+///
+/// All objects share the same `adtcore` properties, the root element and some additional
+/// properties depend on the [`ObjectKind`]. Packages differ too much and are created
+/// through [`crate::models::package::NewPackage`] and [`crate::api::packages::create_package`]
+/// instead.
+use crate::models::serialize::IntoXmlRoot;
+use derive_builder::Builder;
+use serde::{Serialize, ser::SerializeStruct};
+use std::borrow::Cow;
+
+/// The kind of object to create, including the properties specific to that kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectKind<'a> {
+    /// An executable program, `PROG/P`
+    Program,
+    /// An include program, `PROG/I`
+    Include {
+        /// The main program the include is used in, e.g. `Z_MAIN`
+        main_program: Option<Cow<'a, str>>,
+    },
+    /// A global class, `CLAS/OC`
+    Class {
+        /// The class to inherit from, e.g. `CX_STATIC_CHECK`
+        super_class: Option<Cow<'a, str>>,
+    },
+    /// A global interface, `INTF/OI`
+    Interface,
+    /// A function group, `FUGR/F`
+    FunctionGroup,
+}
+
+impl ObjectKind<'_> {
+    /// The object type, e.g. `PROG/P`
+    pub fn object_type(&self) -> &'static str {
+        match self {
+            Self::Program => "PROG/P",
+            Self::Include { .. } => "PROG/I",
+            Self::Class { .. } => "CLAS/OC",
+            Self::Interface => "INTF/OI",
+            Self::FunctionGroup => "FUGR/F",
+        }
+    }
+
+    /// The collection new objects of this kind are created in, relative to `sap/bc/adt/`
+    pub fn collection_uri(&self) -> &'static str {
+        match self {
+            Self::Program => "programs/programs",
+            Self::Include { .. } => "programs/includes",
+            Self::Class { .. } => "oo/classes",
+            Self::Interface => "oo/interfaces",
+            Self::FunctionGroup => "functions/groups",
+        }
+    }
+
+    /// The validation endpoint of this kind, relative to `sap/bc/adt/`
+    pub fn validation_uri(&self) -> &'static str {
+        match self {
+            Self::Program => "programs/validation",
+            Self::Include { .. } => "includes/validation",
+            Self::Class { .. } | Self::Interface => "oo/validation/objectname",
+            Self::FunctionGroup => "functions/validation",
+        }
+    }
+
+    /// The content type of the creation request body
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Program => "application/vnd.sap.adt.programs.programs.v2+xml",
+            Self::Include { .. } => "application/vnd.sap.adt.programs.includes.v2+xml",
+            Self::Class { .. } => "application/vnd.sap.adt.oo.classes.v2+xml",
+            Self::Interface => "application/vnd.sap.adt.oo.interfaces.v5+xml",
+            Self::FunctionGroup => "application/vnd.sap.adt.functions.groups.v3+xml",
+        }
+    }
+
+    /// The root element and its namespace
+    fn root(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Program => (
+                "program:abapProgram",
+                "program",
+                "http://www.sap.com/adt/programs/programs",
+            ),
+            Self::Include { .. } => (
+                "include:abapInclude",
+                "include",
+                "http://www.sap.com/adt/programs/includes",
+            ),
+            Self::Class { .. } => (
+                "class:abapClass",
+                "class",
+                "http://www.sap.com/adt/oo/classes",
+            ),
+            Self::Interface => (
+                "intf:abapInterface",
+                "intf",
+                "http://www.sap.com/adt/oo/interfaces",
+            ),
+            Self::FunctionGroup => (
+                "group:abapFunctionGroup",
+                "group",
+                "http://www.sap.com/adt/functions/groups",
+            ),
+        }
+    }
+}
+
+/// A development object to be created.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::creation::{NewObjectBuilder, ObjectKind};
+///
+/// NewObjectBuilder::default()
+///     .kind(ObjectKind::Class { super_class: None })
+///     .name("ZCL_DEMO")
+///     .description("Demo Class")
+///     .package("$TMP")
+///     .master_language("EN")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct NewObject<'a> {
+    /// The kind of object to create, see [`ObjectKind`]
+    pub(crate) kind: ObjectKind<'a>,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[builder(setter(into))]
+    pub(crate) name: Cow<'a, str>,
+
+    /// The description of the object
+    #[builder(setter(into))]
+    pub(crate) description: Cow<'a, str>,
+
+    /// The package the object is created in, e.g. `$TMP`
+    #[builder(setter(into))]
+    pub(crate) package: Cow<'a, str>,
+
+    /// The user responsible for the object, defaults to the current user.
+    #[builder(setter(into), default)]
+    pub(crate) responsible: Option<Cow<'a, str>>,
+
+    /// Master language of the object, defaults to the logon language.
+    #[builder(setter(into), default)]
+    pub(crate) master_language: Option<Cow<'a, str>>,
+
+    /// The ABAP Version of the object, e.g. `X` for Standard ABAP.
+    #[builder(setter(into), default)]
+    pub(crate) abap_language_version: Option<Cow<'a, str>>,
+}
+
+impl<'a> NewObject<'a> {
+    pub fn kind(&self) -> &ObjectKind<'a> {
+        &self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct NameRef<'a> {
+    #[serde(rename = "@adtcore:name")]
+    name: &'a str,
+}
+
+// The root element depends on the kind of object, thus the struct is serialized manually.
+impl Serialize for NewObject<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let (root, ..) = self.kind.root();
+        let mut state = serializer.serialize_struct(root, 8)?;
+
+        // Attributes need to be serialized before any elements.
+        state.serialize_field("@adtcore:name", &self.name)?;
+        state.serialize_field("@adtcore:type", self.kind.object_type())?;
+        state.serialize_field("@adtcore:description", &self.description)?;
+        state.serialize_field("@adtcore:responsible", &self.responsible)?;
+        state.serialize_field("@adtcore:masterLanguage", &self.master_language)?;
+        state.serialize_field("@adtcore:abapLanguageVersion", &self.abap_language_version)?;
+        state.serialize_field(
+            "adtcore:packageRef",
+            &NameRef {
+                name: &self.package,
+            },
+        )?;
+
+        match &self.kind {
+            ObjectKind::Include {
+                main_program: Some(main_program),
+            } => state.serialize_field("include:contextRef", &NameRef { name: main_program })?,
+            ObjectKind::Class {
+                super_class: Some(super_class),
+            } => state.serialize_field("class:superClassRef", &NameRef { name: super_class })?,
+            _ => {}
+        }
+        state.end()
+    }
+}
+
+impl IntoXmlRoot for NewObject<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        let (_, prefix, namespace) = self.kind.root();
+        vec![
+            (prefix.into(), namespace.into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_new_program() {
+        let program = NewObjectBuilder::default()
+            .kind(ObjectKind::Program)
+            .name("Z_DEMO")
+            .description("Demo Program")
+            .package("$TMP")
+            .responsible("DEVELOPER")
+            .master_language("EN")
+            .abap_language_version("X")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            program.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <program:abapProgram xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:program=\"http://www.sap.com/adt/programs/programs\" \
+                adtcore:name=\"Z_DEMO\" adtcore:type=\"PROG/P\" adtcore:description=\"Demo Program\" \
+                adtcore:responsible=\"DEVELOPER\" adtcore:masterLanguage=\"EN\" adtcore:abapLanguageVersion=\"X\">\
+                <adtcore:packageRef adtcore:name=\"$TMP\" />\
+            </program:abapProgram>"
+        );
+    }
+
+    #[test]
+    fn serialize_new_interface() {
+        let interface = NewObjectBuilder::default()
+            .kind(ObjectKind::Interface)
+            .name("ZIF_DEMO")
+            .description("Demo Interface")
+            .package("$TMP")
+            .master_language("EN")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            interface.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <intf:abapInterface xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:intf=\"http://www.sap.com/adt/oo/interfaces\" \
+                adtcore:name=\"ZIF_DEMO\" adtcore:type=\"INTF/OI\" adtcore:description=\"Demo Interface\" adtcore:masterLanguage=\"EN\">\
+                <adtcore:packageRef adtcore:name=\"$TMP\" />\
+            </intf:abapInterface>"
+        );
+    }
+
+    #[test]
+    fn serialize_new_class_with_super_class() {
+        let class = NewObjectBuilder::default()
+            .kind(ObjectKind::Class {
+                super_class: Some("CX_STATIC_CHECK".into()),
+            })
+            .name("ZCX_DEMO")
+            .description("Demo Exception")
+            .package("$TMP")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            class.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <class:abapClass xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:class=\"http://www.sap.com/adt/oo/classes\" \
+                adtcore:name=\"ZCX_DEMO\" adtcore:type=\"CLAS/OC\" adtcore:description=\"Demo Exception\">\
+                <adtcore:packageRef adtcore:name=\"$TMP\" />\
+                <class:superClassRef adtcore:name=\"CX_STATIC_CHECK\" />\
+            </class:abapClass>"
+        );
+    }
+
+    #[test]
+    fn serialize_new_include_with_main_program() {
+        let include = NewObjectBuilder::default()
+            .kind(ObjectKind::Include {
+                main_program: Some("Z_DEMO".into()),
+            })
+            .name("Z_DEMO_TOP")
+            .description("Top Include")
+            .package("$TMP")
+            .build()
+            .unwrap();
+
+        let body = include.into_xml_root().unwrap();
+        assert!(body.contains("<include:contextRef adtcore:name=\"Z_DEMO\" />"));
+        assert!(body.contains("adtcore:type=\"PROG/I\""));
+    }
+}
@@ -0,0 +1,852 @@
+/// Transport Management (TM) - http://www.sap.com/cts/adt/tm
+///
+/// Transport requests and tasks of the Change and Transport System (CTS), along with
+/// the transport checks of objects that are provided as ASX data.
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::{
+    ParamValue,
+    models::{asx::deserialize_abap_bool, checkrun, serialize::IntoXmlRoot, tpr::TransportStatus},
+};
+
+/// The type of a transport request.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RequestType {
+    /// Transports repository objects, e.g. classes and programs.
+    #[default]
+    Workbench,
+    /// Transports client specific customizing.
+    Customizing,
+}
+
+impl RequestType {
+    /// The type as stored in the system, e.g. `K` for [`RequestType::Workbench`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Workbench => "K",
+            Self::Customizing => "W",
+        }
+    }
+}
+
+impl<'a> ParamValue<'a> for RequestType {
+    fn as_str(&self) -> Cow<'a, str> {
+        Cow::Borrowed(RequestType::as_str(self))
+    }
+}
+
+/// An object recorded in a transport request or task.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename = "tm:abap_object")]
+#[readonly::make]
+pub struct TransportObject {
+    /// The program id, e.g. `R3TR` for complete objects or `LIMU` for parts of objects.
+    #[serde(rename = "@tm:pgmid")]
+    pub pgmid: String,
+
+    /// The object type, e.g. `CLAS`
+    #[serde(rename = "@tm:type")]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[serde(rename = "@tm:name")]
+    pub name: String,
+
+    /// The workbench type of the object, e.g. `CLAS/OC`
+    #[serde(rename = "@tm:wbtype")]
+    pub workbench_type: Option<String>,
+
+    /// The URI of the object, if it can be opened in ADT.
+    #[serde(rename = "@tm:uri")]
+    pub uri: Option<String>,
+
+    /// The description of the object.
+    #[serde(rename = "@tm:obj_desc")]
+    pub description: Option<String>,
+
+    /// The lock status of the object in the transport, e.g. `X` if it is locked.
+    #[serde(rename = "@tm:lock_status")]
+    pub lock_status: Option<String>,
+}
+
+/// A task of a transport request, owned by a single user.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:task")]
+#[readonly::make]
+pub struct Task {
+    /// The number of the task, e.g. `A4HK900090`
+    #[serde(rename = "@tm:number")]
+    pub number: String,
+
+    /// The user the task belongs to.
+    #[serde(rename = "@tm:owner")]
+    pub owner: String,
+
+    /// The description of the task.
+    #[serde(rename = "@tm:desc", default)]
+    pub description: String,
+
+    /// The status of the task.
+    #[serde(rename = "@tm:status")]
+    pub status: TransportStatus,
+
+    /// The URI of the task.
+    #[serde(rename = "@tm:uri")]
+    pub uri: Option<String>,
+
+    /// The objects recorded in the task.
+    #[serde(rename = "tm:abap_object", default)]
+    pub objects: Vec<TransportObject>,
+}
+
+/// A transport request along with its tasks.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:request")]
+#[readonly::make]
+pub struct Request {
+    /// The number of the request, e.g. `A4HK900089`
+    #[serde(rename = "@tm:number")]
+    pub number: String,
+
+    /// The user the request belongs to.
+    #[serde(rename = "@tm:owner")]
+    pub owner: String,
+
+    /// The description of the request.
+    #[serde(rename = "@tm:desc", default)]
+    pub description: String,
+
+    /// The status of the request.
+    #[serde(rename = "@tm:status")]
+    pub status: TransportStatus,
+
+    /// The type of the request, e.g. `K` for workbench requests.
+    #[serde(rename = "@tm:type")]
+    pub request_type: Option<String>,
+
+    /// The target system of the request, e.g. `LOCAL`
+    #[serde(rename = "@tm:target")]
+    pub target: Option<String>,
+
+    /// The URI of the request.
+    #[serde(rename = "@tm:uri")]
+    pub uri: Option<String>,
+
+    /// The tasks of the request.
+    #[serde(rename = "tm:task", default)]
+    pub tasks: Vec<Task>,
+
+    /// The objects recorded in the request itself, i.e. not in one of its tasks.
+    #[serde(rename = "tm:abap_object", default)]
+    pub objects: Vec<TransportObject>,
+}
+
+impl Request {
+    /// Returns the objects of the request and all of its tasks.
+    pub fn all_objects(&self) -> impl Iterator<Item = &TransportObject> {
+        self.objects
+            .iter()
+            .chain(self.tasks.iter().flat_map(|task| task.objects.iter()))
+    }
+
+    /// Returns the task of the given user, if there is one.
+    pub fn task_of(&self, user: &str) -> Option<&Task> {
+        self.tasks
+            .iter()
+            .find(|task| task.owner.eq_ignore_ascii_case(user))
+    }
+}
+
+/// Requests grouped by their status, e.g. all modifiable requests of a target.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct RequestGroup {
+    #[serde(rename = "tm:request", default)]
+    pub requests: Vec<Request>,
+}
+
+/// A transport target along with its requests.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:target")]
+#[readonly::make]
+pub struct Target {
+    /// The name of the target, e.g. `/LOCAL/`
+    #[serde(rename = "@tm:name")]
+    pub name: String,
+
+    /// The description of the target.
+    #[serde(rename = "@tm:desc", default)]
+    pub description: String,
+
+    /// The requests that can still be modified.
+    #[serde(rename = "tm:modifiable", default)]
+    pub modifiable: RequestGroup,
+
+    /// The requests that have already been released.
+    #[serde(rename = "tm:released", default)]
+    pub released: RequestGroup,
+}
+
+/// The targets of a request category, e.g. workbench requests.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct Category {
+    #[serde(rename = "tm:target", default)]
+    pub targets: Vec<Target>,
+}
+
+impl Category {
+    fn requests(&self) -> impl Iterator<Item = &Request> {
+        self.targets.iter().flat_map(|target| {
+            target
+                .modifiable
+                .requests
+                .iter()
+                .chain(target.released.requests.iter())
+        })
+    }
+}
+
+/// Wraps the release reports of a request.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct ReleaseReports {
+    #[serde(rename = "chkrun:checkReport", default)]
+    pub reports: Vec<checkrun::Report>,
+}
+
+/// The root of all transport organizer responses.
+///
+/// Depending on the operation, either the categorized requests of a user, a single
+/// request or the reports of a release are provided.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:root")]
+#[readonly::make]
+pub struct TransportOrganizer {
+    /// The workbench requests, grouped by target.
+    #[serde(rename = "tm:workbench", default)]
+    pub workbench: Category,
+
+    /// The customizing requests, grouped by target.
+    #[serde(rename = "tm:customizing", default)]
+    pub customizing: Category,
+
+    /// The request that was read or created.
+    #[serde(rename = "tm:request")]
+    pub request: Option<Request>,
+
+    /// The reports of a release.
+    #[serde(rename = "tm:releasereports", default)]
+    pub release_reports: ReleaseReports,
+}
+
+impl TransportOrganizer {
+    /// Returns all requests, regardless of their category, target or status.
+    pub fn requests(&self) -> impl Iterator<Item = &Request> {
+        self.workbench
+            .requests()
+            .chain(self.customizing.requests())
+            .chain(self.request.iter())
+    }
+
+    /// Returns all tasks of all requests.
+    pub fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.requests().flat_map(|request| request.tasks.iter())
+    }
+
+    /// Returns the request or task with the given number.
+    pub fn find(&self, number: &str) -> Option<&Request> {
+        self.requests().find(|request| request.number == number)
+    }
+
+    /// Whether none of the release reports contains an error.
+    pub fn is_released(&self) -> bool {
+        self.release_reports.reports.iter().all(|report| {
+            report
+                .messages
+                .as_ref()
+                .is_none_or(|list| list.messages.iter().all(|msg| !msg.severity.is_error()))
+        })
+    }
+}
+
+/// A new transport request, optionally with tasks for further users.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::cts::NewTransportRequestBuilder;
+///
+/// NewTransportRequestBuilder::default()
+///     .description("Demo changes")
+///     .target("LOCAL")
+///     .task_owner("DEVELOPER")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct NewTransportRequest {
+    /// The description of the request.
+    #[builder(setter(into))]
+    description: String,
+
+    /// The type of the request, workbench requests by default.
+    #[builder(default)]
+    request_type: RequestType,
+
+    /// The target system, e.g. `LOCAL` - the default target of the system if not provided.
+    #[builder(setter(into), default)]
+    target: Option<String>,
+
+    /// The CTS project the request is assigned to.
+    #[builder(setter(into), default)]
+    project: Option<String>,
+
+    /// The users that get a task in the request, the creator gets one in any case.
+    #[builder(setter(each(name = "task_owner", into)), default)]
+    task_owners: Vec<String>,
+}
+
+impl Serialize for NewTransportRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        UserAction {
+            action: "newrequest",
+            number: None,
+            target_user: None,
+            request: Some(RequestBody {
+                number: None,
+                description: Some(&self.description),
+                request_type: Some(self.request_type.as_str()),
+                target: Some(self.target.as_deref().unwrap_or_default()),
+                project: Some(self.project.as_deref().unwrap_or_default()),
+                tasks: self
+                    .task_owners
+                    .iter()
+                    .map(|owner| TaskBody { owner })
+                    .collect(),
+                objects: Vec::new(),
+            }),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for NewTransportRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("tm".into(), "http://www.sap.com/cts/adt/tm".into())]
+    }
+}
+
+/// An object to add to a transport request.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::cts::ObjectEntryBuilder;
+///
+/// ObjectEntryBuilder::default()
+///     .object_type("CLAS")
+///     .name("ZCL_DEMO")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder, Serialize)]
+#[serde(rename = "tm:abap_object")]
+pub struct ObjectEntry {
+    /// The program id, `R3TR` by default.
+    #[builder(setter(into), default = "String::from(\"R3TR\")")]
+    #[serde(rename = "@tm:pgmid")]
+    pgmid: String,
+
+    /// The object type, e.g. `CLAS`
+    #[builder(setter(into))]
+    #[serde(rename = "@tm:type")]
+    object_type: String,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[builder(setter(into))]
+    #[serde(rename = "@tm:name")]
+    name: String,
+}
+
+impl From<&TransportObject> for ObjectEntry {
+    fn from(value: &TransportObject) -> Self {
+        Self {
+            pgmid: value.pgmid.clone(),
+            object_type: value.object_type.clone(),
+            name: value.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TaskBody<'a> {
+    #[serde(rename = "@tm:owner")]
+    owner: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct RequestBody<'a> {
+    #[serde(rename = "@tm:number", skip_serializing_if = "Option::is_none")]
+    number: Option<&'a str>,
+
+    #[serde(rename = "@tm:desc", skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+
+    #[serde(rename = "@tm:type", skip_serializing_if = "Option::is_none")]
+    request_type: Option<&'a str>,
+
+    #[serde(rename = "@tm:target", skip_serializing_if = "Option::is_none")]
+    target: Option<&'a str>,
+
+    #[serde(rename = "@tm:cts_project", skip_serializing_if = "Option::is_none")]
+    project: Option<&'a str>,
+
+    #[serde(rename = "tm:task")]
+    tasks: Vec<TaskBody<'a>>,
+
+    #[serde(rename = "tm:abap_object")]
+    objects: Vec<&'a ObjectEntry>,
+}
+
+/// The body of the modifying transport organizer operations, identified by the user action.
+#[derive(Debug, Serialize)]
+#[serde(rename = "tm:root")]
+pub(crate) struct UserAction<'a> {
+    #[serde(rename = "@tm:useraction")]
+    action: &'static str,
+
+    #[serde(rename = "@tm:number", skip_serializing_if = "Option::is_none")]
+    number: Option<&'a str>,
+
+    #[serde(rename = "@tm:targetuser", skip_serializing_if = "Option::is_none")]
+    target_user: Option<&'a str>,
+
+    #[serde(rename = "tm:request", skip_serializing_if = "Option::is_none")]
+    request: Option<RequestBody<'a>>,
+}
+
+impl<'a> UserAction<'a> {
+    fn for_request(action: &'static str, number: &'a str) -> Self {
+        Self {
+            action,
+            number: Some(number),
+            target_user: None,
+            request: None,
+        }
+    }
+
+    fn with_request(mut self, request: RequestBody<'a>) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    pub(crate) fn new_task(number: &'a str, owner: &'a str) -> Self {
+        Self::for_request("newtask", number).with_request(RequestBody {
+            number: Some(number),
+            description: None,
+            request_type: None,
+            target: None,
+            project: None,
+            tasks: vec![TaskBody { owner }],
+            objects: Vec::new(),
+        })
+    }
+
+    pub(crate) fn add_objects(number: &'a str, objects: &'a [ObjectEntry]) -> Self {
+        Self::for_request("addobject", number).with_request(RequestBody {
+            number: Some(number),
+            description: None,
+            request_type: None,
+            target: None,
+            project: None,
+            tasks: Vec::new(),
+            objects: objects.iter().collect(),
+        })
+    }
+
+    pub(crate) fn change_owner(number: &'a str, user: &'a str) -> Self {
+        Self {
+            target_user: Some(user),
+            ..Self::for_request("changeowner", number)
+        }
+    }
+
+    pub(crate) fn change_description(number: &'a str, description: &'a str) -> Self {
+        Self::for_request("changeattributes", number).with_request(RequestBody {
+            number: Some(number),
+            description: Some(description),
+            request_type: None,
+            target: None,
+            project: None,
+            tasks: Vec::new(),
+            objects: Vec::new(),
+        })
+    }
+
+    pub(crate) fn release(number: &'a str) -> Self {
+        Self::for_request("newreleasejobs", number)
+    }
+}
+
+impl IntoXmlRoot for UserAction<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("tm".into(), "http://www.sap.com/cts/adt/tm".into())]
+    }
+}
+
+/// The object to run the transport checks for, i.e. to find out whether and in which
+/// requests a change of the object has to be recorded.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::cts::TransportCheckRequestBuilder;
+///
+/// TransportCheckRequestBuilder::default()
+///     .uri("/sap/bc/adt/oo/classes/zcl_demo/source/main")
+///     .package("ZDEMO")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct TransportCheckRequest {
+    /// The URI of the object, e.g. `/sap/bc/adt/oo/classes/zcl_demo/source/main`
+    uri: String,
+
+    /// The package of the object, the package of a new object respectively.
+    #[builder(default)]
+    package: String,
+
+    /// The operation to check for, `I` (insert/change) by default.
+    #[builder(default = "String::from(\"I\")")]
+    operation: String,
+}
+
+impl TransportCheckRequest {
+    /// Checks the changes of an existing object, the package is determined by the server.
+    pub fn for_uri(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            package: String::new(),
+            operation: String::from("I"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TransportCheckData<'a> {
+    #[serde(rename = "PGMID")]
+    pgmid: &'a str,
+
+    #[serde(rename = "OBJECT")]
+    object: &'a str,
+
+    #[serde(rename = "OBJECTNAME")]
+    object_name: &'a str,
+
+    #[serde(rename = "DEVCLASS")]
+    package: &'a str,
+
+    #[serde(rename = "SUPER_PACKAGE")]
+    super_package: &'a str,
+
+    #[serde(rename = "OPERATION")]
+    operation: &'a str,
+
+    #[serde(rename = "URI")]
+    uri: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct TransportCheckValues<'a> {
+    #[serde(rename = "DATA")]
+    data: TransportCheckData<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "asx:abap")]
+struct TransportCheckBody<'a> {
+    #[serde(rename = "@version")]
+    version: &'static str,
+
+    #[serde(rename = "asx:values")]
+    values: TransportCheckValues<'a>,
+}
+
+impl Serialize for TransportCheckRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        TransportCheckBody {
+            version: "1.0",
+            values: TransportCheckValues {
+                data: TransportCheckData {
+                    pgmid: "",
+                    object: "",
+                    object_name: "",
+                    package: &self.package,
+                    super_package: "",
+                    operation: &self.operation,
+                    uri: &self.uri,
+                },
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for TransportCheckRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("asx".into(), "http://www.sap.com/abapxml".into())]
+    }
+}
+
+/// The header of a request that is suitable for or locks the checked object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "REQ_HEADER")]
+#[readonly::make]
+pub struct RequestHeader {
+    /// The number of the request, e.g. `A4HK900089`
+    #[serde(rename = "TRKORR")]
+    pub number: String,
+
+    /// The type of the request, e.g. `K` for workbench requests.
+    #[serde(rename = "TRFUNCTION", default)]
+    pub request_type: String,
+
+    /// The status of the request.
+    #[serde(rename = "TRSTATUS")]
+    pub status: TransportStatus,
+
+    /// The owner of the request.
+    #[serde(rename = "AS4USER", default)]
+    pub owner: String,
+
+    /// The description of the request.
+    #[serde(rename = "AS4TEXT", default)]
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CtsRequest {
+    #[serde(rename = "REQ_HEADER")]
+    header: RequestHeader,
+}
+
+/// Wraps the requests the checked object can be recorded in.
+#[derive(Debug, Deserialize, Default)]
+pub struct CtsRequests {
+    #[serde(rename = "CTS_REQUEST", default)]
+    requests: Vec<CtsRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LockHolder {
+    #[serde(rename = "REQ_HEADER")]
+    header: RequestHeader,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectLock {
+    #[serde(rename = "LOCK_HOLDER")]
+    holder: LockHolder,
+}
+
+/// Wraps the locks of the checked object.
+#[derive(Debug, Deserialize, Default)]
+pub struct CtsLocks {
+    #[serde(rename = "CTS_OBJECT_LOCK", default)]
+    locks: Vec<ObjectLock>,
+}
+
+/// The result of the transport checks of an object.
+///
+/// Content Type Version `com.sap.adt.transport.service.checkData`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "DATA")]
+#[readonly::make]
+pub struct TransportCheckResult {
+    /// The program id of the object, e.g. `R3TR`
+    #[serde(rename = "PGMID", default)]
+    pub pgmid: String,
+
+    /// The object type, e.g. `CLAS`
+    #[serde(rename = "OBJECT", default)]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[serde(rename = "OBJECTNAME", default)]
+    pub object_name: String,
+
+    /// The package of the object.
+    #[serde(rename = "DEVCLASS", default)]
+    pub package: String,
+
+    /// The software component of the package, `LOCAL` for local objects.
+    #[serde(rename = "DLVUNIT", default)]
+    pub software_component: String,
+
+    /// `S` if the checks succeeded.
+    #[serde(rename = "RESULT", default)]
+    pub result: String,
+
+    /// Whether changes of the object are recorded in transport requests.
+    #[serde(
+        rename = "RECORDING",
+        deserialize_with = "deserialize_abap_bool",
+        default
+    )]
+    pub recording: bool,
+
+    /// The requests the object can be recorded in.
+    #[serde(rename = "REQUESTS", default)]
+    requests: CtsRequests,
+
+    /// The requests the object is locked in.
+    #[serde(rename = "LOCKS", default)]
+    locks: CtsLocks,
+}
+
+impl TransportCheckResult {
+    /// Whether the object is a local object, i.e. changes are never recorded.
+    pub fn is_local(&self) -> bool {
+        self.software_component == "LOCAL" || !self.recording
+    }
+
+    /// Whether a change of the object has to be recorded in a transport request.
+    pub fn requires_transport(&self) -> bool {
+        !self.is_local()
+    }
+
+    /// Returns the requests the object can be recorded in.
+    pub fn requests(&self) -> impl Iterator<Item = &RequestHeader> {
+        self.requests.requests.iter().map(|request| &request.header)
+    }
+
+    /// Returns the request the object is locked in, changes have to be recorded there.
+    pub fn locked_in(&self) -> Option<&RequestHeader> {
+        self.locks.locks.first().map(|lock| &lock.holder.header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::asx::AsxData;
+
+    #[test]
+    fn deserialize_user_transports() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <tm:root xmlns:tm="http://www.sap.com/cts/adt/tm" tm:useraction="">
+                <tm:workbench tm:category="Workbench">
+                    <tm:target tm:name="/LOCAL/" tm:desc="Local Change Requests">
+                        <tm:modifiable tm:status="Modifiable">
+                            <tm:request tm:number="A4HK900089" tm:owner="DEVELOPER" tm:desc="Demo changes" tm:status="D" tm:type="K" tm:uri="/sap/bc/adt/cts/transportrequests/A4HK900089">
+                                <tm:task tm:number="A4HK900090" tm:owner="DEVELOPER" tm:desc="Demo changes" tm:status="D" tm:uri="/sap/bc/adt/cts/transportrequests/A4HK900090">
+                                    <tm:abap_object tm:pgmid="R3TR" tm:type="CLAS" tm:name="ZCL_DEMO" tm:wbtype="CLAS/OC" tm:uri="/sap/bc/adt/oo/classes/zcl_demo" tm:obj_desc="Demo" tm:lock_status="X"/>
+                                </tm:task>
+                                <tm:abap_object tm:pgmid="R3TR" tm:type="PROG" tm:name="ZDEMO"/>
+                            </tm:request>
+                        </tm:modifiable>
+                        <tm:released tm:status="Released">
+                            <tm:request tm:number="A4HK900080" tm:owner="DEVELOPER" tm:desc="Old changes" tm:status="R"/>
+                        </tm:released>
+                    </tm:target>
+                </tm:workbench>
+                <tm:customizing tm:category="Customizing"/>
+            </tm:root>"#;
+
+        let result: TransportOrganizer = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.requests().count(), 2);
+        assert_eq!(result.tasks().count(), 1);
+
+        let request = result.find("A4HK900089").unwrap();
+        assert_eq!(request.status, TransportStatus::Modifiable);
+        assert_eq!(request.all_objects().count(), 2);
+        assert_eq!(request.task_of("developer").unwrap().number, "A4HK900090");
+        assert_eq!(
+            result.find("A4HK900080").unwrap().status,
+            TransportStatus::Released
+        );
+    }
+
+    #[test]
+    fn serialize_new_transport_request() {
+        let request = NewTransportRequestBuilder::default()
+            .description("Demo changes")
+            .target("LOCAL")
+            .task_owner("DEVELOPER")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><tm:root xmlns:tm="http://www.sap.com/cts/adt/tm" tm:useraction="newrequest"><tm:request tm:desc="Demo changes" tm:type="K" tm:target="LOCAL" tm:cts_project=""><tm:task tm:owner="DEVELOPER" /></tm:request></tm:root>"#
+        );
+    }
+
+    #[test]
+    fn serialize_change_owner() {
+        let action = UserAction::change_owner("A4HK900089", "OTHER");
+
+        assert_eq!(
+            action.into_xml_root().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><tm:root xmlns:tm="http://www.sap.com/cts/adt/tm" tm:useraction="changeowner" tm:number="A4HK900089" tm:targetuser="OTHER" />"#
+        );
+    }
+
+    #[test]
+    fn deserialize_transport_check_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <PGMID>R3TR</PGMID>
+                        <OBJECT>CLAS</OBJECT>
+                        <OBJECTNAME>ZCL_DEMO</OBJECTNAME>
+                        <OPERATION>I</OPERATION>
+                        <DEVCLASS>ZDEMO</DEVCLASS>
+                        <CTEXT>Demo</CTEXT>
+                        <KORRFLAG>X</KORRFLAG>
+                        <AS4USER>DEVELOPER</AS4USER>
+                        <PDEVCLASS/>
+                        <DLVUNIT>HOME</DLVUNIT>
+                        <NAMESPACE>/0CUST/</NAMESPACE>
+                        <RESULT>S</RESULT>
+                        <RECORDING>X</RECORDING>
+                        <EXISTING_REQ_ONLY/>
+                        <REQUESTS>
+                            <CTS_REQUEST>
+                                <REQ_HEADER>
+                                    <TRKORR>A4HK900089</TRKORR>
+                                    <TRFUNCTION>K</TRFUNCTION>
+                                    <TRSTATUS>D</TRSTATUS>
+                                    <TARSYSTEM/>
+                                    <AS4USER>DEVELOPER</AS4USER>
+                                    <AS4DATE>2025-01-10</AS4DATE>
+                                    <AS4TIME>10:00:00</AS4TIME>
+                                    <AS4TEXT>Demo changes</AS4TEXT>
+                                    <CLIENT>001</CLIENT>
+                                </REQ_HEADER>
+                            </CTS_REQUEST>
+                        </REQUESTS>
+                        <LOCKS/>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#;
+
+        let result: AsxData<TransportCheckResult> = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.requires_transport());
+        assert!(result.locked_in().is_none());
+
+        let request = result.requests().next().unwrap();
+        assert_eq!(request.number, "A4HK900089");
+        assert_eq!(request.status, TransportStatus::Modifiable);
+    }
+}
@@ -0,0 +1,495 @@
+/// Data Preview - http://www.sap.com/adt/dataPreview
+///
+/// The result of a query is returned column by column, [`TableData::rows`] turns it into
+/// rows of typed [`Value`]s.
+use chrono::{NaiveDate, NaiveTime};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq},
+};
+use std::{fmt, str::FromStr};
+
+/// The ABAP type of a column, which determines how its values are converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbapType {
+    /// Text of a fixed length, `C` or `CHAR`
+    Character,
+    /// Text of digits, e.g. document numbers, `N` or `NUMC`
+    NumericText,
+    /// Text of variable length, `g` or `STRING`
+    String,
+    /// Integers of any length, e.g. `I` or `INT8`
+    Integer,
+    /// Packed numbers, e.g. `P`, `DEC` or `CURR`
+    Packed,
+    /// Floating point numbers, including the decimal floating point types
+    Float,
+    /// Dates in the format `YYYYMMDD`
+    Date,
+    /// Times in the format `HHMMSS`
+    Time,
+    /// Byte sequences, e.g. `X`, `RAW` or `y`
+    Bytes,
+    /// Any other type, e.g. `CLNT` or `LANG`, handled as text.
+    Other(String),
+}
+
+impl FromStr for AbapType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "C" | "CHAR" => Self::Character,
+            "N" | "NUMC" => Self::NumericText,
+            "g" | "STRING" | "SSTRING" => Self::String,
+            "I" | "b" | "s" | "8" | "INT1" | "INT2" | "INT4" | "INT8" => Self::Integer,
+            "P" | "DEC" | "CURR" | "QUAN" => Self::Packed,
+            "F" | "a" | "e" | "FLTP" | "DECFLOAT16" | "DECFLOAT34" | "D16N" | "D34N" => Self::Float,
+            "D" | "DATS" | "DATN" => Self::Date,
+            "T" | "TIMS" | "TIMN" => Self::Time,
+            "X" | "y" | "RAW" | "RAWSTRING" => Self::Bytes,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for AbapType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+impl AbapType {
+    /// Converts a value of the column, values that do not match the type are kept as text.
+    pub fn convert(&self, raw: &str) -> Value {
+        let value = raw.trim();
+        let converted = match self {
+            Self::Integer => parse_signed(value).and_then(|v| v.parse().ok().map(Value::Integer)),
+            Self::Packed => parse_signed(value)
+                .filter(|v| is_decimal(v))
+                .map(Value::Decimal),
+            Self::Float => parse_signed(value).and_then(|v| v.parse().ok().map(Value::Number)),
+            Self::Date => match value {
+                "" | "00000000" => Some(Value::Null),
+                _ => NaiveDate::parse_from_str(value, "%Y%m%d")
+                    .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                    .ok()
+                    .map(Value::Date),
+            },
+            Self::Time => match value {
+                "" | "000000" => Some(Value::Null),
+                _ => NaiveTime::parse_from_str(value, "%H%M%S")
+                    .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M:%S"))
+                    .ok()
+                    .map(Value::Time),
+            },
+            _ => None,
+        };
+        converted.unwrap_or_else(|| Value::Text(raw.to_string()))
+    }
+}
+
+/// ABAP writes the sign of negative numbers behind them, e.g. `12.50-`
+fn parse_signed(value: &str) -> Option<String> {
+    match value.strip_suffix('-') {
+        Some(number) => Some(format!("-{}", number.trim())),
+        None if value.is_empty() => None,
+        None => Some(value.to_string()),
+    }
+}
+
+/// Whether the value consists of digits with an optional sign and decimal point.
+fn is_decimal(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    !integer.is_empty()
+        && integer.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// A value of a cell, converted according to the [`AbapType`] of its column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Integer(i64),
+    Number(f64),
+    /// A packed number, kept as its digits to not lose precision, e.g. `-12.50`
+    Decimal(String),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    /// An empty or initial date or time, i.e. `00000000` or `000000`
+    Null,
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns numbers as float, including integers - packed numbers may lose precision.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Number(value) => Some(*value),
+            Self::Decimal(digits) => digits.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the exact digits of a packed number, e.g. `12345678901234567.89`
+    pub fn as_decimal(&self) -> Option<&str> {
+        match self {
+            Self::Decimal(digits) => Some(digits),
+            _ => None,
+        }
+    }
+
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        match self {
+            Self::Date(date) => Some(*date),
+            _ => None,
+        }
+    }
+
+    pub fn as_time(&self) -> Option<NaiveTime> {
+        match self {
+            Self::Time(time) => Some(*time),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Decimal(digits) => write!(f, "{digits}"),
+            Self::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            Self::Time(time) => write!(f, "{}", time.format("%H:%M:%S")),
+            Self::Null => Ok(()),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            // Packed numbers are exported as their digits, a float would round them.
+            Self::Text(text) | Self::Decimal(text) => serializer.serialize_str(text),
+            Self::Integer(value) => serializer.serialize_i64(*value),
+            Self::Number(value) => serializer.serialize_f64(*value),
+            Self::Date(_) | Self::Time(_) => serializer.collect_str(self),
+            Self::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+/// The description of a column of the result.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dataPreview:metadata")]
+#[readonly::make]
+pub struct ColumnMetadata {
+    /// The name of the column, e.g. `CARRID`
+    #[serde(rename = "@dataPreview:name")]
+    pub name: String,
+
+    /// The ABAP type of the column.
+    #[serde(rename = "@dataPreview:type")]
+    pub abap_type: AbapType,
+
+    /// The description of the column, e.g. `Airline Code`
+    #[serde(rename = "@dataPreview:description", default)]
+    pub description: String,
+
+    /// Whether the column is part of the key.
+    #[serde(rename = "@dataPreview:keyAttribute", default)]
+    pub is_key: bool,
+
+    /// The length of the column.
+    #[serde(rename = "@dataPreview:length", default)]
+    pub length: u32,
+}
+
+/// Wraps the values of a column.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct DataSet {
+    #[serde(rename = "dataPreview:data", default)]
+    pub values: Vec<String>,
+}
+
+/// A column of the result with its values.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dataPreview:columns")]
+#[readonly::make]
+pub struct Column {
+    #[serde(rename = "dataPreview:metadata")]
+    pub metadata: ColumnMetadata,
+
+    #[serde(rename = "dataPreview:dataSet", default)]
+    pub data: DataSet,
+}
+
+impl Column {
+    /// Returns the value at the given row converted according to the type of the column.
+    pub fn value(&self, row: usize) -> Option<Value> {
+        self.data
+            .values
+            .get(row)
+            .map(|raw| self.metadata.abap_type.convert(raw))
+    }
+}
+
+/// The result of a data preview query.
+///
+/// Serializes as a sequence of rows, each a map from the column name to the value, e.g. to
+/// export it as JSON - [`TableData::to_csv`] exports it as CSV.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "dataPreview:tableData")]
+#[readonly::make]
+pub struct TableData {
+    /// The number of rows matching the query, which may be more than the returned ones.
+    #[serde(rename = "dataPreview:totalRows", default)]
+    pub total_rows: u64,
+
+    /// The query as executed by the server, e.g. with the row limit applied.
+    #[serde(rename = "dataPreview:executedQueryString", default)]
+    pub executed_query: String,
+
+    /// The execution time of the query in milliseconds.
+    #[serde(rename = "dataPreview:queryExecutionTime", default)]
+    pub execution_time: f64,
+
+    #[serde(rename = "dataPreview:columns", default)]
+    pub columns: Vec<Column>,
+}
+
+impl TableData {
+    /// Returns the number of returned rows.
+    pub fn row_count(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| column.data.values.len())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the index of the column with the given name, ignoring the case.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|column| column.metadata.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        (0..self.row_count()).map(|index| Row { table: self, index })
+    }
+
+    /// Exports the result as CSV, the first line holds the names of the columns.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        let header: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| csv_field(&column.metadata.name))
+            .collect();
+        csv.push_str(&header.join(","));
+        csv.push('\n');
+
+        for row in self.rows() {
+            let fields: Vec<_> = row
+                .values()
+                .map(|value| csv_field(&value.map(|v| v.to_string()).unwrap_or_default()))
+                .collect();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl Serialize for TableData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.row_count()))?;
+        for row in self.rows() {
+            seq.serialize_element(&row)?;
+        }
+        seq.end()
+    }
+}
+
+/// A row of a [`TableData`]
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    table: &'a TableData,
+    index: usize,
+}
+
+impl Row<'_> {
+    /// Returns the value of the column with the given name, ignoring the case.
+    pub fn get(&self, column: &str) -> Option<Value> {
+        self.table
+            .column_index(column)
+            .and_then(|index| self.table.columns[index].value(self.index))
+    }
+
+    /// Returns the values in the order of the columns, `None` for missing values.
+    pub fn values(&self) -> impl Iterator<Item = Option<Value>> + '_ {
+        self.table
+            .columns
+            .iter()
+            .map(|column| column.value(self.index))
+    }
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.table.columns.len()))?;
+        for column in &self.table.columns {
+            map.serialize_entry(&column.metadata.name, &column.value(self.index))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE_DATA: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <dataPreview:tableData xmlns:dataPreview="http://www.sap.com/adt/dataPreview">
+            <dataPreview:totalRows>2</dataPreview:totalRows>
+            <dataPreview:isHanaAnalyticalView>false</dataPreview:isHanaAnalyticalView>
+            <dataPreview:executedQueryString>SELECT SFLIGHT~CARRID, SFLIGHT~FLDATE, SFLIGHT~PRICE, SFLIGHT~SEATSOCC FROM SFLIGHT</dataPreview:executedQueryString>
+            <dataPreview:queryExecutionTime>3.25</dataPreview:queryExecutionTime>
+            <dataPreview:columns>
+                <dataPreview:metadata dataPreview:name="CARRID" dataPreview:type="C" dataPreview:description="Airline Code" dataPreview:keyAttribute="true" dataPreview:colType="" dataPreview:isKeyFigure="false" dataPreview:length="3"/>
+                <dataPreview:dataSet>
+                    <dataPreview:data>AA</dataPreview:data>
+                    <dataPreview:data>L,H</dataPreview:data>
+                </dataPreview:dataSet>
+            </dataPreview:columns>
+            <dataPreview:columns>
+                <dataPreview:metadata dataPreview:name="FLDATE" dataPreview:type="D" dataPreview:description="Flight date" dataPreview:keyAttribute="true" dataPreview:length="8"/>
+                <dataPreview:dataSet>
+                    <dataPreview:data>20240115</dataPreview:data>
+                    <dataPreview:data>00000000</dataPreview:data>
+                </dataPreview:dataSet>
+            </dataPreview:columns>
+            <dataPreview:columns>
+                <dataPreview:metadata dataPreview:name="PRICE" dataPreview:type="P" dataPreview:description="Airfare" dataPreview:keyAttribute="false" dataPreview:length="16"/>
+                <dataPreview:dataSet>
+                    <dataPreview:data>422.94</dataPreview:data>
+                    <dataPreview:data>12.50-</dataPreview:data>
+                </dataPreview:dataSet>
+            </dataPreview:columns>
+            <dataPreview:columns>
+                <dataPreview:metadata dataPreview:name="SEATSOCC" dataPreview:type="I" dataPreview:description="Occupied seats" dataPreview:keyAttribute="false" dataPreview:length="10"/>
+                <dataPreview:dataSet>
+                    <dataPreview:data>374</dataPreview:data>
+                    <dataPreview:data>0</dataPreview:data>
+                </dataPreview:dataSet>
+            </dataPreview:columns>
+        </dataPreview:tableData>"#;
+
+    #[test]
+    fn deserialize_table_data() {
+        let result: TableData = serde_xml_rs::from_str(TABLE_DATA).unwrap();
+        assert_eq!(result.total_rows, 2);
+        assert_eq!(result.columns.len(), 4);
+        assert_eq!(result.columns[0].metadata.description, "Airline Code");
+        assert!(result.columns[0].metadata.is_key);
+        assert_eq!(result.columns[2].metadata.abap_type, AbapType::Packed);
+
+        let rows: Vec<_> = result.rows().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get("carrid"), Some(Value::Text("AA".into())));
+        assert_eq!(
+            rows[0].get("FLDATE").and_then(|v| v.as_date()),
+            NaiveDate::from_ymd_opt(2024, 1, 15)
+        );
+        assert_eq!(rows[0].get("SEATSOCC").and_then(|v| v.as_i64()), Some(374));
+        assert_eq!(rows[1].get("PRICE").and_then(|v| v.as_f64()), Some(-12.5));
+        assert_eq!(
+            rows[1].get("PRICE").as_ref().and_then(|v| v.as_decimal()),
+            Some("-12.50")
+        );
+        assert_eq!(rows[1].get("FLDATE"), Some(Value::Null));
+    }
+
+    #[test]
+    fn export_table_data() {
+        let result: TableData = serde_xml_rs::from_str(TABLE_DATA).unwrap();
+        assert_eq!(
+            result.to_csv(),
+            "CARRID,FLDATE,PRICE,SEATSOCC\nAA,2024-01-15,422.94,374\n\"L,H\",,-12.50,0\n"
+        );
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"[{"CARRID":"AA","FLDATE":"2024-01-15","PRICE":"422.94","SEATSOCC":374},{"CARRID":"L,H","FLDATE":null,"PRICE":"-12.50","SEATSOCC":0}]"#
+        );
+    }
+
+    #[test]
+    fn packed_numbers_keep_their_precision() {
+        let value = AbapType::Packed.convert(" 12345678901234567.89");
+        assert_eq!(value.as_decimal(), Some("12345678901234567.89"));
+        assert_eq!(value.to_string(), "12345678901234567.89");
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#""12345678901234567.89""#
+        );
+
+        assert_eq!(
+            AbapType::Packed.convert("1.2.3"),
+            Value::Text("1.2.3".into())
+        );
+    }
+
+    #[test]
+    fn initial_times_are_null() {
+        assert_eq!(AbapType::Time.convert(""), Value::Null);
+        assert_eq!(AbapType::Time.convert("000000"), Value::Null);
+        assert_eq!(
+            AbapType::Time.convert("134501").as_time(),
+            NaiveTime::from_hms_opt(13, 45, 1)
+        );
+    }
+}
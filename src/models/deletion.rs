@@ -0,0 +1,309 @@
+/// Deletion - http://www.sap.com/adt/deletion
+///
+/// Objects are deleted in bulk, the server reports the outcome for each object separately.
+use crate::models::{adtcore, serialize::IntoXmlRoot};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// An object to be checked for deletion or to be deleted.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::deletion::DeletionObjectBuilder;
+///
+/// DeletionObjectBuilder::default()
+///     .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Debug, Serialize, Clone)]
+#[serde(rename = "del:object")]
+#[builder(setter(strip_option))]
+pub struct DeletionObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    #[builder(setter(into))]
+    uri: String,
+
+    /// The handle of a lock obtained on the object in the same user session.
+    #[serde(rename = "@del:lockHandle", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into), default)]
+    lock_handle: Option<String>,
+}
+
+impl From<&adtcore::ObjectReference> for DeletionObject {
+    fn from(value: &adtcore::ObjectReference) -> Self {
+        Self {
+            uri: value.uri.clone(),
+            lock_handle: None,
+        }
+    }
+}
+
+/// A collection of [`DeletionObject`]s along with the transport to record the deletion in.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::deletion::{DeletionObjectBuilder, DeletionRequestBuilder};
+///
+/// DeletionRequestBuilder::default()
+///     .object(
+///         DeletionObjectBuilder::default()
+///             .uri("/sap/bc/adt/oo/classes/zcl_wegwerf")
+///             .build()
+///             .unwrap(),
+///     )
+///     .transport("A4HK900089")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Builder, Debug, Serialize, Clone)]
+#[serde(rename = "del:deletionRequest")]
+#[builder(setter(strip_option))]
+pub struct DeletionRequest {
+    /// The transport to record the deletion in, not required for local objects.
+    #[serde(
+        rename = "del:transportNumber",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[builder(setter(into), default)]
+    transport: Option<String>,
+
+    #[serde(rename = "del:object")]
+    #[builder(setter(each(name = "object", into)))]
+    objects: Vec<DeletionObject>,
+}
+
+impl DeletionRequest {
+    pub fn objects(&self) -> &[DeletionObject] {
+        &self.objects
+    }
+}
+
+impl IntoXmlRoot for DeletionRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("del".into(), "http://www.sap.com/adt/deletion".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+/// A message the server attached to an object, e.g. why it cannot be deleted.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "del:message")]
+#[readonly::make]
+pub struct DeletionMessage {
+    /// The kind of message, e.g `W` for **Warning**, or `E` for **Error**.
+    #[serde(rename = "@del:type")]
+    pub kind: String,
+
+    /// The text of the message
+    #[serde(rename = "@del:text")]
+    pub text: String,
+}
+
+/// Whether an object can be deleted, derived from the flags of a [`CheckedObject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeletionStatus {
+    /// The object can be deleted right away.
+    Deletable,
+    /// The object can be deleted once a transport is provided.
+    TransportRequired,
+    /// The object is still used by other objects.
+    BlockedByUsages,
+    /// The object cannot be deleted for another reason, see the messages.
+    Blocked,
+}
+
+/// The result of the deletion check of a single object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "del:object")]
+#[readonly::make]
+pub struct CheckedObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the object, e.g. `PROG/P`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: Option<String>,
+
+    /// The name of the object, e.g. `ZWEGWERF1`
+    #[serde(rename = "@adtcore:name")]
+    pub name: Option<String>,
+
+    /// Whether the object can be deleted at all
+    #[serde(rename = "@del:isDeletable", default)]
+    pub is_deletable: bool,
+
+    /// Whether the deletion has to be recorded in a transport
+    #[serde(rename = "@del:isTransportRequired", default)]
+    pub is_transport_required: bool,
+
+    /// Whether the object is still used by other objects
+    #[serde(rename = "@del:hasUsages", default)]
+    pub has_usages: bool,
+
+    /// Messages explaining the result
+    #[serde(rename = "del:message", default)]
+    pub messages: Vec<DeletionMessage>,
+}
+
+impl CheckedObject {
+    pub fn status(&self) -> DeletionStatus {
+        if self.has_usages {
+            DeletionStatus::BlockedByUsages
+        } else if !self.is_deletable {
+            DeletionStatus::Blocked
+        } else if self.is_transport_required {
+            DeletionStatus::TransportRequired
+        } else {
+            DeletionStatus::Deletable
+        }
+    }
+}
+
+/// Wraps a collection of [`CheckedObject`]s
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "del:checkResult")]
+#[readonly::make]
+pub struct DeletionCheckResult {
+    #[serde(rename = "del:object", default)]
+    pub objects: Vec<CheckedObject>,
+}
+
+impl DeletionCheckResult {
+    /// Whether all objects can be deleted, possibly requiring a transport.
+    pub fn all_deletable(&self) -> bool {
+        self.objects.iter().all(|object| {
+            matches!(
+                object.status(),
+                DeletionStatus::Deletable | DeletionStatus::TransportRequired
+            )
+        })
+    }
+}
+
+/// The outcome of the deletion of a single object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "del:object")]
+#[readonly::make]
+pub struct DeletedObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/programs/programs/zwegwerf1`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the object, e.g. `PROG/P`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: Option<String>,
+
+    /// The name of the object, e.g. `ZWEGWERF1`
+    #[serde(rename = "@adtcore:name")]
+    pub name: Option<String>,
+
+    /// Whether the object was deleted
+    #[serde(rename = "@del:isDeleted", default)]
+    pub is_deleted: bool,
+
+    /// Messages explaining why the object was not deleted
+    #[serde(rename = "del:message", default)]
+    pub messages: Vec<DeletionMessage>,
+}
+
+/// Wraps a collection of [`DeletedObject`]s
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "del:deletionResult")]
+#[readonly::make]
+pub struct DeletionResult {
+    #[serde(rename = "del:object", default)]
+    pub objects: Vec<DeletedObject>,
+}
+
+impl DeletionResult {
+    /// The objects that could not be deleted.
+    pub fn failed(&self) -> impl Iterator<Item = &DeletedObject> {
+        self.objects.iter().filter(|object| !object.is_deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_deletion_request() {
+        let request = DeletionRequestBuilder::default()
+            .object(
+                DeletionObjectBuilder::default()
+                    .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                    .lock_handle("ABC123")
+                    .build()
+                    .unwrap(),
+            )
+            .object(
+                DeletionObjectBuilder::default()
+                    .uri("/sap/bc/adt/oo/classes/zcl_wegwerf")
+                    .build()
+                    .unwrap(),
+            )
+            .transport("A4HK900089")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <del:deletionRequest xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:del=\"http://www.sap.com/adt/deletion\">\
+                <del:transportNumber>A4HK900089</del:transportNumber>\
+                <del:object adtcore:uri=\"/sap/bc/adt/programs/programs/zwegwerf1\" del:lockHandle=\"ABC123\" />\
+                <del:object adtcore:uri=\"/sap/bc/adt/oo/classes/zcl_wegwerf\" />\
+            </del:deletionRequest>"
+        );
+    }
+
+    #[test]
+    fn deserialize_check_result() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <del:checkResult xmlns:del="http://www.sap.com/adt/deletion" xmlns:adtcore="http://www.sap.com/adt/core">
+                <del:object adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1" del:isDeletable="true" del:isTransportRequired="false" del:hasUsages="false"/>
+                <del:object adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo1" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO1" del:isDeletable="false" del:isTransportRequired="false" del:hasUsages="true">
+                    <del:message del:type="E" del:text="Object ZCL_DEMO1 is still used by ZDEMO1"/>
+                </del:object>
+                <del:object adtcore:uri="/sap/bc/adt/oo/interfaces/zif_demo" adtcore:type="INTF/OI" adtcore:name="ZIF_DEMO" del:isDeletable="true" del:isTransportRequired="true" del:hasUsages="false"/>
+            </del:checkResult>"#;
+
+        let result: DeletionCheckResult = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.objects.len(), 3);
+        assert_eq!(result.objects[0].status(), DeletionStatus::Deletable);
+        assert_eq!(result.objects[1].status(), DeletionStatus::BlockedByUsages);
+        assert_eq!(result.objects[1].messages.len(), 1);
+        assert_eq!(
+            result.objects[2].status(),
+            DeletionStatus::TransportRequired
+        );
+        assert!(!result.all_deletable());
+    }
+
+    #[test]
+    fn deserialize_deletion_result() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <del:deletionResult xmlns:del="http://www.sap.com/adt/deletion" xmlns:adtcore="http://www.sap.com/adt/core">
+                <del:object adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1" del:isDeleted="true"/>
+                <del:object adtcore:uri="/sap/bc/adt/oo/classes/zcl_wegwerf" adtcore:type="CLAS/OC" adtcore:name="ZCL_WEGWERF" del:isDeleted="false">
+                    <del:message del:type="E" del:text="Object ZCL_WEGWERF is locked by DEVELOPER"/>
+                </del:object>
+            </del:deletionResult>"#;
+
+        let result: DeletionResult = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.objects.len(), 2);
+        let failed: Vec<_> = result.failed().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].name.as_deref(), Some("ZCL_WEGWERF"));
+    }
+}
@@ -0,0 +1,526 @@
+/// Function Groups (FUGR) - http://www.sap.com/adt/functions/groups
+///
+/// Covers function groups, their includes and function modules. The parameters of a
+/// function module are part of its source code, see [`FunctionSignature`].
+use crate::{
+    error::{InvalidFunctionSignature, ResponseError},
+    models::{abapsource, adtcore, atom},
+    response::DeserializeResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// Represents an ABAP function group
+#[derive(Debug, Deserialize)]
+#[serde(rename = "group:abapFunctionGroup")]
+#[readonly::make]
+pub struct FunctionGroupMetadata {
+    /// The name of the function group
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the function group, should be `FUGR/F`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the function group was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the function group descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the function group was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who last changed this function group
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The description of the function group
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The language of the function group, e.g. `EN`
+    #[serde(rename = "@adtcore:language")]
+    pub language: String,
+
+    /// The relative uri to fetch the source code of the main program, e.g. `SAPLZDEMO`
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// The user who is responsible for this function group
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the function group
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The system this function group belongs to
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: String,
+
+    /// The ABAP Version of the function group
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Relative URLs to related function group Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the package the function group belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Syntax Configuration of the function group
+    #[serde(rename = "abapsource:syntaxConfiguration")]
+    pub syntax_configuration: Option<abapsource::SyntaxConfiguration>,
+}
+
+/// How a function module is processed, i.e. whether it can be called remotely.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProcessingType {
+    /// A regular function module
+    Normal,
+    /// A remote-enabled function module
+    Rfc,
+    /// An update function module
+    Update,
+}
+
+/// Represents an ABAP function module
+#[derive(Debug, Deserialize)]
+#[serde(rename = "fmodule:abapFunctionModule")]
+#[readonly::make]
+pub struct FunctionModuleMetadata {
+    /// The name of the function module
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the function module, should be `FUGR/FF`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the function module was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the function module descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The user who last changed this function module
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The description of the function module
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// How the function module is processed, see [`ProcessingType`]
+    #[serde(rename = "@fmodule:processingType")]
+    pub processing_type: ProcessingType,
+
+    /// Whether the function module supports basXML for remote calls
+    #[serde(rename = "@fmodule:basXMLEnabled", default)]
+    pub basxml_enabled: bool,
+
+    /// The release state of the function module, e.g. `notReleased`
+    #[serde(rename = "@fmodule:releaseState")]
+    pub release_state: Option<String>,
+
+    /// The relative uri to fetch the function module source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Relative URLs to related function module Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the function group the function module belongs to
+    #[serde(rename = "adtcore:containerRef")]
+    pub function_group: adtcore::ObjectReference,
+}
+
+/// Represents an include of an ABAP function group, e.g. `LZDEMOTOP`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "finclude:abapFunctionGroupInclude")]
+#[readonly::make]
+pub struct FunctionGroupIncludeMetadata {
+    /// The name of the include
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the include, should be `FUGR/I`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the include was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the include descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The user who last changed this include
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The description of the include
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The relative uri to fetch the include source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Relative URLs to related include Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the function group the include belongs to
+    #[serde(rename = "adtcore:containerRef")]
+    pub function_group: adtcore::ObjectReference,
+}
+
+/// The section of a function module signature a parameter is declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    Importing,
+    Exporting,
+    Changing,
+    Tables,
+}
+
+/// How the type of a parameter is specified, parameters without typing are generic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Typing {
+    /// `TYPE <type>`
+    Type(String),
+    /// `TYPE REF TO <type>`
+    TypeRefTo(String),
+    /// `LIKE <data object>`
+    Like(String),
+    /// `STRUCTURE <structure>`, obsolete typing of `TABLES` parameters
+    Structure(String),
+}
+
+/// A single parameter of a function module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[readonly::make]
+pub struct Parameter {
+    /// The section the parameter is declared in
+    pub kind: ParameterKind,
+
+    /// The name of the parameter, e.g. `IV_NAME`
+    pub name: String,
+
+    /// Whether the parameter is passed by value, i.e. declared as `VALUE(...)`
+    pub pass_by_value: bool,
+
+    /// The typing of the parameter, `None` for generic parameters
+    pub typing: Option<Typing>,
+
+    /// Whether the parameter is optional
+    pub optional: bool,
+
+    /// The default value of the parameter, e.g. `'X'` or `abap_true`
+    pub default: Option<String>,
+}
+
+/// The signature of a function module as declared at the start of its source code.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::function::{FunctionSignature, ParameterKind};
+///
+/// let signature: FunctionSignature = "FUNCTION z_demo\n  IMPORTING\n    VALUE(iv_name) TYPE string\n  EXCEPTIONS\n    not_found.\nENDFUNCTION."
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(signature.parameters[0].kind, ParameterKind::Importing);
+/// assert_eq!(signature.exceptions, vec!["NOT_FOUND"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[readonly::make]
+pub struct FunctionSignature {
+    /// The name of the function module
+    pub name: String,
+
+    /// The `IMPORTING`, `EXPORTING`, `CHANGING` and `TABLES` parameters in order of declaration
+    pub parameters: Vec<Parameter>,
+
+    /// The classic exceptions declared with `EXCEPTIONS`
+    pub exceptions: Vec<String>,
+
+    /// The class based exceptions declared with `RAISING`
+    pub raising: Vec<String>,
+}
+
+impl FunctionSignature {
+    /// Returns all parameters declared in the given section.
+    pub fn parameters_of(&self, kind: ParameterKind) -> impl Iterator<Item = &Parameter> {
+        self.parameters.iter().filter(move |p| p.kind == kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Parameters(ParameterKind),
+    Exceptions,
+    Raising,
+}
+
+impl FromStr for FunctionSignature {
+    type Err = InvalidFunctionSignature;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let tokens = signature_tokens(source);
+        let mut tokens = tokens.iter().map(String::as_str).peekable();
+        let invalid = |reason: &str| InvalidFunctionSignature(reason.to_string());
+
+        if !tokens
+            .next()
+            .is_some_and(|t| t.eq_ignore_ascii_case("FUNCTION"))
+        {
+            return Err(invalid("source does not start with FUNCTION"));
+        }
+        let name = tokens
+            .next()
+            .ok_or_else(|| invalid("function name is missing"))?
+            .to_uppercase();
+
+        let mut signature = FunctionSignature {
+            name,
+            parameters: Vec::new(),
+            exceptions: Vec::new(),
+            raising: Vec::new(),
+        };
+        let mut section = None;
+
+        while let Some(token) = tokens.next() {
+            let upper = token.to_uppercase();
+            let next_section = match upper.as_str() {
+                "IMPORTING" => Some(Section::Parameters(ParameterKind::Importing)),
+                "EXPORTING" => Some(Section::Parameters(ParameterKind::Exporting)),
+                "CHANGING" => Some(Section::Parameters(ParameterKind::Changing)),
+                "TABLES" => Some(Section::Parameters(ParameterKind::Tables)),
+                "EXCEPTIONS" => Some(Section::Exceptions),
+                "RAISING" => Some(Section::Raising),
+                _ => None,
+            };
+            if next_section.is_some() {
+                section = next_section;
+                continue;
+            }
+
+            match section {
+                None => return Err(invalid(&format!("unexpected '{token}' before any section"))),
+                Some(Section::Exceptions) => signature.exceptions.push(upper),
+                Some(Section::Raising) => signature.raising.push(strip_wrapper(&upper).1.into()),
+                Some(Section::Parameters(kind)) => {
+                    let (wrapper, name) = strip_wrapper(&upper);
+                    let mut parameter = Parameter {
+                        kind,
+                        name: name.to_string(),
+                        pass_by_value: wrapper == Some("VALUE"),
+                        typing: None,
+                        optional: false,
+                        default: None,
+                    };
+
+                    // Consume the additions of the parameter until the next one starts.
+                    while let Some(addition) = tokens.peek().map(|t| t.to_uppercase()) {
+                        match addition.as_str() {
+                            "TYPE" | "LIKE" | "STRUCTURE" => {
+                                tokens.next();
+                                let mut target = tokens
+                                    .next()
+                                    .ok_or_else(|| invalid("typing without type"))?
+                                    .to_uppercase();
+                                let typing = match addition.as_str() {
+                                    "TYPE" if target == "REF" => {
+                                        tokens.next(); // TO
+                                        target = tokens
+                                            .next()
+                                            .ok_or_else(|| invalid("reference without type"))?
+                                            .to_uppercase();
+                                        Typing::TypeRefTo(target)
+                                    }
+                                    "TYPE" => Typing::Type(target),
+                                    "LIKE" => Typing::Like(target),
+                                    _ => Typing::Structure(target),
+                                };
+                                parameter.typing = Some(typing);
+                            }
+                            "OPTIONAL" => {
+                                tokens.next();
+                                parameter.optional = true;
+                            }
+                            "DEFAULT" => {
+                                tokens.next();
+                                let value = tokens
+                                    .next()
+                                    .ok_or_else(|| invalid("default without value"))?;
+                                parameter.default = Some(value.to_string());
+                                // Parameters with a default value are always optional.
+                                parameter.optional = true;
+                            }
+                            _ => break,
+                        }
+                    }
+                    signature.parameters.push(parameter);
+                }
+            }
+        }
+        Ok(signature)
+    }
+}
+
+impl DeserializeResponse for FunctionSignature {
+    fn deserialize_response(body: String) -> Result<Self, ResponseError> {
+        body.parse()
+            .map_err(|err: InvalidFunctionSignature| ResponseError::InvalidBody(err.to_string()))
+    }
+}
+
+/// Splits `VALUE(iv_name)` into `(Some("VALUE"), "iv_name")`
+fn strip_wrapper(token: &str) -> (Option<&str>, &str) {
+    match token.split_once('(') {
+        Some((wrapper, rest)) => (Some(wrapper), rest.trim_end_matches(')')),
+        None => (None, token),
+    }
+}
+
+/// Tokenizes the `FUNCTION` statement, i.e. everything up to the first period,
+/// skipping comments and keeping literals intact.
+fn signature_tokens(source: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+
+    for line in source.lines() {
+        if line.starts_with('*') {
+            continue;
+        }
+        let mut current = String::new();
+        let mut literal = None;
+
+        for c in line.chars() {
+            match (literal, c) {
+                (Some(delimiter), c) if c == delimiter => {
+                    current.push(c);
+                    literal = None;
+                }
+                (Some(_), c) => current.push(c),
+                (None, '\'' | '`') => {
+                    current.push(c);
+                    literal = Some(c);
+                }
+                (None, '"') => break,
+                (None, '.') => {
+                    if !current.is_empty() {
+                        tokens.push(current);
+                    }
+                    return tokens;
+                }
+                (None, c) if c.is_whitespace() => {
+                    if !current.is_empty() {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                }
+                (None, c) => current.push(c),
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_function_module_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <fmodule:abapFunctionModule fmodule:releaseState="notReleased" fmodule:processingType="rfc" fmodule:basXMLEnabled="false" abapsource:sourceUri="source/main" adtcore:name="Z_DEMO" adtcore:type="FUGR/FF" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:changedBy="DEVELOPER" adtcore:description="Demo" adtcore:language="EN" xmlns:fmodule="http://www.sap.com/adt/functions/fmodules" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440011"/>
+                <adtcore:containerRef adtcore:uri="/sap/bc/adt/functions/groups/zdemo" adtcore:type="FUGR/F" adtcore:name="ZDEMO" adtcore:packageName="$TMP"/>
+            </fmodule:abapFunctionModule>"#;
+
+        let result: FunctionModuleMetadata = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.processing_type, ProcessingType::Rfc);
+        assert_eq!(result.function_group.name, "ZDEMO");
+    }
+
+    #[test]
+    fn parse_function_signature() {
+        let source = r#"FUNCTION z_demo
+  IMPORTING
+    VALUE(iv_name) TYPE string
+    iv_count TYPE i DEFAULT 1
+    iv_flag TYPE abap_bool DEFAULT 'X' " flag with a comment
+    REFERENCE(io_obj) TYPE REF TO cl_demo OPTIONAL
+  EXPORTING
+    VALUE(ev_result) TYPE string
+  CHANGING
+    cv_generic
+  TABLES
+    it_flights STRUCTURE sflight OPTIONAL
+  EXCEPTIONS
+    not_found
+    failed.
+*"----------------------------------------------------------------------
+  ev_result = iv_name.
+ENDFUNCTION."#;
+
+        let signature: FunctionSignature = source.parse().unwrap();
+        assert_eq!(signature.name, "Z_DEMO");
+        assert_eq!(signature.parameters.len(), 7);
+        assert_eq!(signature.parameters_of(ParameterKind::Importing).count(), 4);
+
+        let name = &signature.parameters[0];
+        assert!(name.pass_by_value);
+        assert_eq!(name.typing, Some(Typing::Type("STRING".into())));
+        assert!(!name.optional);
+
+        let flag = &signature.parameters[2];
+        assert_eq!(flag.default.as_deref(), Some("'X'"));
+        assert!(flag.optional);
+
+        let obj = &signature.parameters[3];
+        assert!(!obj.pass_by_value);
+        assert_eq!(obj.typing, Some(Typing::TypeRefTo("CL_DEMO".into())));
+
+        assert_eq!(signature.parameters[5].typing, None);
+        assert_eq!(
+            signature.parameters[6].typing,
+            Some(Typing::Structure("SFLIGHT".into()))
+        );
+        assert_eq!(signature.exceptions, vec!["NOT_FOUND", "FAILED"]);
+    }
+
+    #[test]
+    fn parse_signature_without_parameters() {
+        let signature: FunctionSignature = "FUNCTION z_empty.\nENDFUNCTION.".parse().unwrap();
+        assert!(signature.parameters.is_empty());
+
+        let signature: FunctionSignature =
+            "FUNCTION z_raising\n  RAISING\n    cx_demo.\nENDFUNCTION."
+                .parse()
+                .unwrap();
+        assert_eq!(signature.raising, vec!["CX_DEMO"]);
+    }
+
+    #[test]
+    fn reject_invalid_signature() {
+        assert!("REPORT z_demo.".parse::<FunctionSignature>().is_err());
+    }
+}
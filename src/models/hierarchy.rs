@@ -0,0 +1,102 @@
+/// Type Hierarchy - http://www.sap.com/adt/ris/hierarchy
+use serde::Deserialize;
+
+/// A class or interface of a type hierarchy.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "hierarchy:entry")]
+#[readonly::make]
+pub struct HierarchyEntry {
+    /// The URI of the type, e.g. `/sap/bc/adt/oo/classes/zcl_demo`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the entry, e.g. `CLAS/OC` or `INTF/OI`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the type, e.g. `ZCL_DEMO`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The description of the type.
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+
+    /// The type the entry directly inherits from or implements, e.g. `ZIF_DEMO`
+    #[serde(rename = "@hierarchy:superTypeName")]
+    pub super_type: Option<String>,
+
+    /// Whether the type is abstract.
+    #[serde(rename = "@hierarchy:isAbstract", default)]
+    pub is_abstract: bool,
+
+    /// Whether the type is final.
+    #[serde(rename = "@hierarchy:isFinal", default)]
+    pub is_final: bool,
+
+    /// Whether the type defines or implements the element the hierarchy was requested for.
+    #[serde(rename = "@hierarchy:hasDefOrImpl", default)]
+    pub has_definition_or_implementation: bool,
+}
+
+impl HierarchyEntry {
+    /// Whether the entry is an interface rather than a class.
+    pub fn is_interface(&self) -> bool {
+        self.object_type.starts_with("INTF")
+    }
+}
+
+/// The super or sub types of the class or interface at a position.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "hierarchy:typeHierarchy")]
+#[readonly::make]
+pub struct TypeHierarchy {
+    #[serde(rename = "hierarchy:entry", default)]
+    pub entries: Vec<HierarchyEntry>,
+}
+
+impl TypeHierarchy {
+    /// Returns the classes of the hierarchy.
+    pub fn classes(&self) -> impl Iterator<Item = &HierarchyEntry> {
+        self.entries.iter().filter(|entry| !entry.is_interface())
+    }
+
+    /// Returns the interfaces of the hierarchy.
+    pub fn interfaces(&self) -> impl Iterator<Item = &HierarchyEntry> {
+        self.entries.iter().filter(|entry| entry.is_interface())
+    }
+
+    /// Returns the types that directly inherit from or implement the given type.
+    pub fn children_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a HierarchyEntry> {
+        self.entries.iter().filter(move |entry| {
+            entry
+                .super_type
+                .as_deref()
+                .is_some_and(|super_type| super_type.eq_ignore_ascii_case(name))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_sub_types() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <hierarchy:typeHierarchy xmlns:hierarchy="http://www.sap.com/adt/ris/hierarchy" xmlns:adtcore="http://www.sap.com/adt/core">
+                <hierarchy:entry adtcore:uri="/sap/bc/adt/oo/interfaces/zif_demo" adtcore:type="INTF/OI" adtcore:name="ZIF_DEMO" adtcore:description="Demo" hierarchy:hasDefOrImpl="true"/>
+                <hierarchy:entry adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO" hierarchy:superTypeName="ZIF_DEMO" hierarchy:isAbstract="true" hierarchy:isFinal="false" hierarchy:hasDefOrImpl="true"/>
+                <hierarchy:entry adtcore:uri="/sap/bc/adt/oo/classes/zcl_demo_sub" adtcore:type="CLAS/OC" adtcore:name="ZCL_DEMO_SUB" hierarchy:superTypeName="ZCL_DEMO" hierarchy:isFinal="true"/>
+            </hierarchy:typeHierarchy>"#;
+
+        let result: TypeHierarchy = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.interfaces().count(), 1);
+        assert_eq!(result.classes().count(), 2);
+
+        let implementers: Vec<_> = result.children_of("zif_demo").collect();
+        assert_eq!(implementers.len(), 1);
+        assert!(implementers[0].is_abstract);
+        assert!(result.children_of("ZCL_DEMO").next().unwrap().is_final);
+    }
+}
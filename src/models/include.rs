@@ -0,0 +1,135 @@
+/// Includes (PROG/I) - http://www.sap.com/adt/programs/includes
+use crate::models::{abapsource, adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Represents an ABAP include program
+#[derive(Debug, Deserialize)]
+#[serde(rename = "include:abapInclude")]
+#[readonly::make]
+pub struct AbapInclude {
+    /// The name of the include
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the include, should be `PROG/I`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the include was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the include descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the include was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who last changed this include
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The description of the include
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The language of the include, e.g. `EN`
+    #[serde(rename = "@adtcore:language")]
+    pub language: String,
+
+    /// Whether the include is currently locked by the editor
+    #[serde(rename = "@include:lockedByEditor", default)]
+    pub locked_by_editor: bool,
+
+    /// The relative uri to fetch the include source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// The user who is responsible for this include
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the include
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The system this include belongs to
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: String,
+
+    /// The ABAP Version of the include
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Relative URLs to related include Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the package the include belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Syntax Configuration of the include
+    #[serde(rename = "abapsource:syntaxConfiguration")]
+    pub syntax_configuration: Option<abapsource::SyntaxConfiguration>,
+
+    /// The main program the include was last edited in, see [`ContextRef`]
+    #[serde(rename = "include:contextRef")]
+    pub context: Option<ContextRef>,
+}
+
+/// Reference to the main program that provides the context of an include.
+///
+/// All attributes are missing if the include has not been assigned a context yet.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "include:contextRef")]
+#[readonly::make]
+pub struct ContextRef {
+    /// The URI of the main program, e.g. `/sap/bc/adt/programs/programs/z_badi_check`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: Option<String>,
+
+    /// The type of the main program, e.g. `PROG/P` or `FUGR/F`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: Option<String>,
+
+    /// The name of the main program
+    #[serde(rename = "@adtcore:name")]
+    pub name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_abap_include_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <include:abapInclude include:lockedByEditor="false" abapsource:sourceUri="source/main" abapsource:fixPointArithmetic="true" abapsource:activeUnicodeCheck="true" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="X" adtcore:name="ZBADICHECK_INC" adtcore:type="PROG/I" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:createdAt="2023-03-08T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:description="Include" adtcore:descriptionTextLimit="70" adtcore:language="EN" xmlns:include="http://www.sap.com/adt/programs/includes" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440011"/>
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                <include:contextRef adtcore:uri="/sap/bc/adt/programs/programs/z_badi_check" adtcore:type="PROG/P" adtcore:name="Z_BADI_CHECK"/>
+            </include:abapInclude>"#;
+
+        let result: AbapInclude = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(
+            result.context.unwrap().uri.as_deref(),
+            Some("/sap/bc/adt/programs/programs/z_badi_check")
+        );
+    }
+
+    #[test]
+    fn deserialize_include_without_context() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <include:abapInclude abapsource:sourceUri="source/main" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:name="ZINC" adtcore:type="PROG/I" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:createdAt="2023-03-08T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:description="Include" adtcore:language="EN" xmlns:include="http://www.sap.com/adt/programs/includes" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                <include:contextRef/>
+            </include:abapInclude>"#;
+
+        let result: AbapInclude = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.context.unwrap().uri.is_none());
+    }
+}
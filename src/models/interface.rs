@@ -0,0 +1,243 @@
+/// Interfaces (INTF) - http://www.sap.com/adt/oo/interfaces
+use crate::models::{
+    abapsource, adtcore, atom,
+    creation::{NewObject, ObjectKind},
+    serialize::IntoXmlRoot,
+};
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Represents a global ABAP interface
+#[derive(Debug, Deserialize)]
+#[serde(rename = "intf:abapInterface")]
+#[readonly::make]
+pub struct InterfaceMetadata {
+    /// The name of the interface
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the interface, should be `INTF/OI`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The datetime that the interface was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The version of the interface descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the interface was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who last changed this interface
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The user who created this interface
+    #[serde(rename = "@adtcore:createdBy")]
+    pub created_by: Option<String>,
+
+    /// The description of the interface
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The character limit of the interface description
+    #[serde(rename = "@adtcore:descriptionTextLimit")]
+    pub description_text_limit: Option<i32>,
+
+    /// The language of the interface, e.g. `EN`
+    #[serde(rename = "@adtcore:language")]
+    pub language: String,
+
+    /// Whether the interface is modeled, e.g. generated from a BOPF business object
+    #[serde(rename = "@abapoo:modeled", default)]
+    pub modeled: bool,
+
+    /// The relative uri to fetch the interface source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Whether the interface supports fixed point arithmetic
+    #[serde(rename = "@abapsource:fixPointArithmetic")]
+    pub fix_point_arithmetic: bool,
+
+    /// Whether unicode checks are active for this interface
+    #[serde(rename = "@abapsource:activeUnicodeCheck")]
+    pub unicode_check_active: bool,
+
+    /// The user who is responsible for this interface
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the interface
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The system this interface belongs to
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: String,
+
+    /// The ABAP Version of the interface
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Relative URLs to related interface Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Reference to the package the interface belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Syntax Configuration of the interface
+    #[serde(rename = "abapsource:syntaxConfiguration")]
+    pub syntax_configuration: abapsource::SyntaxConfiguration,
+}
+
+impl InterfaceMetadata {
+    /// The etag of the plain text source of the interface, if provided.
+    pub fn source_etag(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.kind.as_deref() == Some("text/plain"))
+            .and_then(|link| link.etag.as_deref())
+    }
+}
+
+/// An interface to be created.
+///
+/// Same as a [`NewObject`] of [`ObjectKind::Interface`], which it is converted into to be
+/// created through [`crate::api::object::create_object`].
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::interface::NewInterfaceBuilder;
+///
+/// NewInterfaceBuilder::default()
+///     .name("ZIF_DEMO")
+///     .description("Demo Interface")
+///     .package("$TMP")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct NewInterface<'a> {
+    /// The name of the interface, e.g. `ZIF_DEMO`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the interface
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+
+    /// The package the interface is created in, e.g. `$TMP`
+    #[builder(setter(into))]
+    package: Cow<'a, str>,
+
+    /// The user responsible for the interface, defaults to the current user.
+    #[builder(setter(into), default)]
+    responsible: Option<Cow<'a, str>>,
+
+    /// Master language of the interface, defaults to the logon language.
+    #[builder(setter(into), default)]
+    master_language: Option<Cow<'a, str>>,
+
+    /// The ABAP Version of the interface, e.g. `X` for Standard ABAP.
+    #[builder(setter(into), default)]
+    abap_language_version: Option<Cow<'a, str>>,
+}
+
+impl NewInterface<'_> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+}
+
+impl<'a> From<NewInterface<'a>> for NewObject<'a> {
+    fn from(value: NewInterface<'a>) -> Self {
+        Self {
+            kind: ObjectKind::Interface,
+            name: value.name,
+            description: value.description,
+            package: value.package,
+            responsible: value.responsible,
+            master_language: value.master_language,
+            abap_language_version: value.abap_language_version,
+        }
+    }
+}
+
+impl Serialize for NewInterface<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        NewObject::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for NewInterface<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        NewObject::from(self.clone()).namespaces()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_abap_interface_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <intf:abapInterface abapoo:modeled="false" abapsource:sourceUri="source/main" abapsource:fixPointArithmetic="false" abapsource:activeUnicodeCheck="false" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="X" adtcore:name="ZIF_DEMO" adtcore:type="INTF/OI" adtcore:changedAt="2025-08-30T21:49:44Z" adtcore:version="active" adtcore:createdAt="2023-03-08T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:createdBy="DEVELOPER" adtcore:description="Demo" adtcore:descriptionTextLimit="60" adtcore:language="EN" xmlns:intf="http://www.sap.com/adt/oo/interfaces" xmlns:abapoo="http://www.sap.com/adt/oo" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main/versions" rel="http://www.sap.com/adt/relations/versions"/>
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202508302149440011"/>
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                <abapsource:syntaxConfiguration>
+                    <abapsource:language>
+                        <abapsource:version>X</abapsource:version>
+                        <abapsource:description>Standard ABAP</abapsource:description>
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="/sap/bc/adt/abapsource/parsers/rnd/grammar" rel="http://www.sap.com/adt/relations/abapsource/parser" type="text/plain" title="Standard ABAP" etag="757"/>
+                    </abapsource:language>
+                </abapsource:syntaxConfiguration>
+            </intf:abapInterface>"#;
+
+        let result: InterfaceMetadata = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.name, "ZIF_DEMO");
+        assert_eq!(result.source_etag(), Some("202508302149440011"));
+    }
+
+    #[test]
+    fn serialize_new_interface() {
+        let interface = NewInterfaceBuilder::default()
+            .name("ZIF_DEMO")
+            .description("Demo Interface")
+            .package("$TMP")
+            .master_language("EN")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            interface.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <intf:abapInterface xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:intf=\"http://www.sap.com/adt/oo/interfaces\" \
+                adtcore:name=\"ZIF_DEMO\" adtcore:type=\"INTF/OI\" adtcore:description=\"Demo Interface\" adtcore:masterLanguage=\"EN\">\
+                <adtcore:packageRef adtcore:name=\"$TMP\" />\
+            </intf:abapInterface>"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+/// Named Items - http://www.sap.com/adt/nameditem
+///
+/// Generic key/description lists, typically returned as value help for properties.
+use serde::Deserialize;
+
+/// Wraps a collection of [`NamedItem`]s
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "nameditem:namedItemList")]
+#[readonly::make]
+pub struct NamedItemList {
+    /// The total number of items that matched, may exceed the number of returned items.
+    #[serde(rename = "nameditem:totalItemCount")]
+    pub total_item_count: i32,
+
+    #[serde(rename = "nameditem:namedItem", default)]
+    pub items: Vec<NamedItem>,
+}
+
+/// A single value, e.g. an owner `DEVELOPER` or an application component `BC-DWB`.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "nameditem:namedItem")]
+#[readonly::make]
+pub struct NamedItem {
+    /// The technical value of the item, this is what is used in a filter.
+    #[serde(rename = "nameditem:name")]
+    pub name: String,
+
+    /// The description of the item, for example the full name of a user.
+    #[serde(rename = "nameditem:description", default)]
+    pub description: Option<String>,
+
+    /// Additional data of the item, depends on the kind of value that was requested.
+    #[serde(rename = "nameditem:data", default)]
+    pub data: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_named_item_list() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <nameditem:namedItemList xmlns:nameditem="http://www.sap.com/adt/nameditem">
+                        <nameditem:totalItemCount>2</nameditem:totalItemCount>
+                        <nameditem:namedItem>
+                            <nameditem:name>DDIC</nameditem:name>
+                            <nameditem:description/>
+                        </nameditem:namedItem>
+                        <nameditem:namedItem>
+                            <nameditem:name>DEVELOPER</nameditem:name>
+                            <nameditem:description>Developer</nameditem:description>
+                            <nameditem:data>DEVELOPER</nameditem:data>
+                        </nameditem:namedItem>
+                    </nameditem:namedItemList>"#;
+
+        let result: NamedItemList = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.total_item_count, 2);
+        assert_eq!(result.items[1].name, "DEVELOPER");
+        assert_eq!(result.items[1].description.as_deref(), Some("Developer"));
+    }
+
+    #[test]
+    fn deserialize_empty_named_item_list() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <nameditem:namedItemList xmlns:nameditem="http://www.sap.com/adt/nameditem">
+                        <nameditem:totalItemCount>0</nameditem:totalItemCount>
+                    </nameditem:namedItemList>"#;
+
+        let result: NamedItemList = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.items.is_empty());
+    }
+}
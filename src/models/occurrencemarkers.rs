@@ -0,0 +1,96 @@
+/// Occurrence Markers - http://www.sap.com/adt/abapsource/occurrencemarkers
+use serde::Deserialize;
+
+use crate::models::abapsource::Position;
+
+/// How an identifier is used at an occurrence.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OccurrenceKind {
+    /// The identifier is declared, e.g. `DATA lv_counter TYPE i.`
+    Definition,
+    /// The value is read.
+    Read,
+    /// The value is changed.
+    Write,
+    /// Any other usage, e.g. a keyword belonging to the one at the position.
+    #[serde(other)]
+    Other,
+}
+
+/// An occurrence of the identifier within the source.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "occ:occurrence")]
+#[readonly::make]
+pub struct Occurrence {
+    /// The line of the occurrence, starting at `1`
+    #[serde(rename = "@occ:line")]
+    pub line: u32,
+
+    /// The column the occurrence starts at, starting at `0`
+    #[serde(rename = "@occ:column")]
+    pub column: u32,
+
+    /// The length of the occurrence.
+    #[serde(rename = "@occ:length")]
+    pub length: u32,
+
+    /// How the identifier is used at the occurrence.
+    #[serde(rename = "@occ:kind")]
+    pub kind: OccurrenceKind,
+}
+
+impl Occurrence {
+    /// The position the occurrence starts at.
+    pub fn start(&self) -> Position {
+        Position::new(self.line, self.column)
+    }
+
+    /// The position after the occurrence.
+    pub fn end(&self) -> Position {
+        Position::new(self.line, self.column + self.length)
+    }
+}
+
+/// All occurrences of the identifier at a position, e.g. to highlight them in an editor.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "occ:occurrenceMarkers")]
+#[readonly::make]
+pub struct OccurrenceMarkers {
+    /// The identifier the occurrences were determined for.
+    #[serde(rename = "@occ:identifier")]
+    pub identifier: Option<String>,
+
+    #[serde(rename = "occ:occurrence", default)]
+    pub occurrences: Vec<Occurrence>,
+}
+
+impl OccurrenceMarkers {
+    /// Returns the occurrence where the identifier is defined, if it is defined in the source.
+    pub fn definition(&self) -> Option<&Occurrence> {
+        self.occurrences
+            .iter()
+            .find(|occurrence| occurrence.kind == OccurrenceKind::Definition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_occurrence_markers() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <occ:occurrenceMarkers xmlns:occ="http://www.sap.com/adt/abapsource/occurrencemarkers" occ:identifier="LV_COUNTER">
+                <occ:occurrence occ:line="2" occ:column="5" occ:length="10" occ:kind="definition"/>
+                <occ:occurrence occ:line="3" occ:column="0" occ:length="10" occ:kind="write"/>
+                <occ:occurrence occ:line="4" occ:column="3" occ:length="10" occ:kind="read"/>
+            </occ:occurrenceMarkers>"#;
+
+        let result: OccurrenceMarkers = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.occurrences.len(), 3);
+        assert_eq!(result.definition().unwrap().start(), Position::new(2, 5));
+        assert_eq!(result.occurrences[1].kind, OccurrenceKind::Write);
+        assert_eq!(result.occurrences[2].end(), Position::new(4, 13));
+    }
+}
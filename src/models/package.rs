@@ -0,0 +1,514 @@
+/// Packages (PAK) - http://www.sap.com/adt/packages
+///
+/// Provides the metadata of packages (`DEVC/K`) and the body to create new ones.
+use crate::{
+    ParamValue,
+    models::{adtcore, atom, serialize::IntoXmlRoot},
+};
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The type of a package, see domain `DEVCLASS_TYPE` on the ABAP System.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PackageType {
+    /// A package that development objects can be assigned to - default.
+    Development,
+    /// A package that only contains other packages, no development objects.
+    Structure,
+    /// A package that contains packages and objects required for package interfaces.
+    Main,
+}
+
+impl PackageType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Structure => "structure",
+            Self::Main => "main",
+        }
+    }
+}
+
+impl<'a> ParamValue<'a> for PackageType {
+    fn as_str(&self) -> Cow<'a, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+/// Represents a package in the ABAP Workbench
+#[derive(Debug, Deserialize)]
+#[serde(rename = "pak:package")]
+#[readonly::make]
+pub struct Package {
+    /// The name of the package, e.g. `$TMP` or `SADT_TOOLS_CORE`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the package, should be `DEVC/K`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the package
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the package, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: Option<String>,
+
+    /// The datetime that the package was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The user who created the package
+    #[serde(rename = "@adtcore:createdBy")]
+    pub created_by: Option<String>,
+
+    /// The datetime that the package was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: Option<DateTime<Utc>>,
+
+    /// The user who last changed the package
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: Option<String>,
+
+    /// The user who is responsible for the package
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: Option<String>,
+
+    /// Master language of the package, e.g. `EN`
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: Option<String>,
+
+    /// The system the package originates from
+    #[serde(rename = "@adtcore:masterSystem")]
+    pub master_system: Option<String>,
+
+    /// Relative URLs to related package Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// General attributes of the package, e.g. its [`PackageType`]
+    #[serde(rename = "pak:attributes")]
+    pub attributes: Attributes,
+
+    /// The package this package is part of, the name is empty for top level packages.
+    #[serde(rename = "pak:superPackage")]
+    pub super_package: Option<SuperPackage>,
+
+    /// The application component the package is assigned to.
+    #[serde(rename = "pak:applicationComponent")]
+    pub application_component: Option<NamedProperty>,
+
+    /// Transport related properties, i.e. software component and transport layer.
+    #[serde(rename = "pak:transport")]
+    pub transport: Option<TransportProperties>,
+
+    /// The packages directly below this package.
+    #[serde(rename = "pak:subPackages", default)]
+    pub sub_packages: SubPackages,
+}
+
+/// General attributes of a [`Package`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "pak:attributes")]
+#[readonly::make]
+pub struct Attributes {
+    /// The type of the package, see [`PackageType`]
+    #[serde(rename = "@pak:packageType")]
+    pub package_type: PackageType,
+
+    /// Whether the package is encapsulated, i.e. only objects exposed by its package
+    /// interfaces may be used from outside.
+    #[serde(rename = "@pak:isEncapsulated", default)]
+    pub is_encapsulated: bool,
+
+    /// Whether objects may be added to the package
+    #[serde(rename = "@pak:isAddingObjectsAllowed", default)]
+    pub is_adding_objects_allowed: bool,
+
+    /// Whether changes to the objects of the package are recorded in transports.
+    #[serde(rename = "@pak:recordChanges", default)]
+    pub record_changes: bool,
+}
+
+/// Reference to the super package of a [`Package`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "pak:superPackage")]
+#[readonly::make]
+pub struct SuperPackage {
+    /// The name of the super package, `None` for top level packages.
+    #[serde(rename = "@adtcore:name")]
+    pub name: Option<String>,
+
+    /// The URI of the super package, e.g. `/sap/bc/adt/packages/sadt_main`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: Option<String>,
+
+    /// The description of the super package
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+}
+
+/// A property identified by a name, such as a software component or transport layer.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct NamedProperty {
+    /// The technical name of the property, e.g. `HOME` or `LOCAL`
+    #[serde(rename = "@pak:name", default)]
+    pub name: String,
+
+    /// The description of the property
+    #[serde(rename = "@pak:description")]
+    pub description: Option<String>,
+}
+
+/// Transport related properties of a [`Package`]
+#[derive(Debug, Deserialize)]
+#[serde(rename = "pak:transport")]
+#[readonly::make]
+pub struct TransportProperties {
+    /// The software component of the package, e.g. `HOME` or `LOCAL` for local packages.
+    #[serde(rename = "pak:softwareComponent")]
+    pub software_component: Option<NamedProperty>,
+
+    /// The transport layer of the package, empty for local packages.
+    #[serde(rename = "pak:transportLayer")]
+    pub transport_layer: Option<NamedProperty>,
+}
+
+/// Wraps a collection of references to sub packages.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "pak:subPackages")]
+#[readonly::make]
+pub struct SubPackages {
+    #[serde(rename = "pak:packageRef", default)]
+    pub packages: Vec<adtcore::PackageRef>,
+}
+
+/// A package to be created.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::package::{NewPackageBuilder, PackageType};
+///
+/// NewPackageBuilder::default()
+///     .name("ZMY_PACKAGE")
+///     .description("My Package")
+///     .super_package("ZMY_PARENT")
+///     .package_type(PackageType::Development)
+///     .software_component("HOME")
+///     .transport_layer("ZA4H")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct NewPackage<'a> {
+    /// The name of the package, e.g. `ZMY_PACKAGE` or `$MY_LOCAL_PACKAGE`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the package
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+
+    /// The type of the package, defaults to [`PackageType::Development`]
+    #[builder(default = PackageType::Development)]
+    package_type: PackageType,
+
+    /// The package the new package is created in.
+    #[builder(setter(into), default)]
+    super_package: Option<Cow<'a, str>>,
+
+    /// The user responsible for the package, defaults to the current user.
+    #[builder(setter(into), default)]
+    responsible: Option<Cow<'a, str>>,
+
+    /// Master language of the package, defaults to the logon language.
+    #[builder(setter(into), default)]
+    master_language: Option<Cow<'a, str>>,
+
+    /// The ABAP Version of the package, e.g. `X` for Standard ABAP.
+    #[builder(setter(into), default)]
+    abap_language_version: Option<Cow<'a, str>>,
+
+    /// The software component, e.g. `HOME`, or `LOCAL` for local packages.
+    #[builder(setter(into), default)]
+    software_component: Option<Cow<'a, str>>,
+
+    /// The transport layer, not required for local packages.
+    #[builder(setter(into), default)]
+    transport_layer: Option<Cow<'a, str>>,
+
+    /// The application component, e.g. `BC-DWB`
+    #[builder(setter(into), default)]
+    application_component: Option<Cow<'a, str>>,
+}
+
+impl NewPackage<'_> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn package_type(&self) -> &PackageType {
+        &self.package_type
+    }
+
+    pub fn super_package(&self) -> Option<&str> {
+        self.super_package.as_deref()
+    }
+
+    pub fn software_component(&self) -> Option<&str> {
+        self.software_component.as_deref()
+    }
+
+    pub fn transport_layer(&self) -> Option<&str> {
+        self.transport_layer.as_deref()
+    }
+
+    pub fn application_component(&self) -> Option<&str> {
+        self.application_component.as_deref()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct AdtcoreName<'a> {
+    #[serde(rename = "@adtcore:name", skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct PakName<'a> {
+    #[serde(rename = "@pak:name")]
+    name: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PakPackageType<'a> {
+    #[serde(rename = "@pak:packageType")]
+    package_type: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PakTransport<'a> {
+    #[serde(rename = "pak:softwareComponent")]
+    software_component: PakName<'a>,
+
+    #[serde(rename = "pak:transportLayer")]
+    transport_layer: PakName<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct Empty {}
+
+/// The XML body representation of a [`NewPackage`]
+#[derive(Debug, Serialize)]
+#[serde(rename = "pak:package")]
+struct NewPackageBody<'a> {
+    #[serde(rename = "@adtcore:name")]
+    name: &'a str,
+
+    #[serde(rename = "@adtcore:type")]
+    object_type: &'static str,
+
+    #[serde(rename = "@adtcore:description")]
+    description: &'a str,
+
+    #[serde(
+        rename = "@adtcore:responsible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    responsible: Option<&'a str>,
+
+    #[serde(
+        rename = "@adtcore:masterLanguage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    master_language: Option<&'a str>,
+
+    #[serde(
+        rename = "@adtcore:abapLanguageVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    abap_language_version: Option<&'a str>,
+
+    #[serde(rename = "adtcore:packageRef")]
+    package_ref: AdtcoreName<'a>,
+
+    #[serde(rename = "pak:attributes")]
+    attributes: PakPackageType<'a>,
+
+    #[serde(rename = "pak:superPackage")]
+    super_package: AdtcoreName<'a>,
+
+    #[serde(rename = "pak:applicationComponent")]
+    application_component: PakName<'a>,
+
+    #[serde(rename = "pak:transport")]
+    transport: PakTransport<'a>,
+
+    #[serde(rename = "pak:translation")]
+    translation: Empty,
+
+    #[serde(rename = "pak:useAccesses")]
+    use_accesses: Empty,
+
+    #[serde(rename = "pak:packageInterfaces")]
+    package_interfaces: Empty,
+
+    #[serde(rename = "pak:subPackages")]
+    sub_packages: Empty,
+}
+
+// The body repeats some of the values in nested elements, thus it is serialized through
+// a seperate representation rather than deriving it on the public model.
+impl Serialize for NewPackage<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        NewPackageBody {
+            name: &self.name,
+            object_type: "DEVC/K",
+            description: &self.description,
+            responsible: self.responsible.as_deref(),
+            master_language: self.master_language.as_deref(),
+            abap_language_version: self.abap_language_version.as_deref(),
+            package_ref: AdtcoreName {
+                name: Some(&self.name),
+            },
+            attributes: PakPackageType {
+                package_type: self.package_type.as_str(),
+            },
+            super_package: AdtcoreName {
+                name: self.super_package.as_deref(),
+            },
+            application_component: PakName {
+                name: self.application_component.as_deref().unwrap_or_default(),
+            },
+            transport: PakTransport {
+                software_component: PakName {
+                    name: self.software_component.as_deref().unwrap_or_default(),
+                },
+                transport_layer: PakName {
+                    name: self.transport_layer.as_deref().unwrap_or_default(),
+                },
+            },
+            translation: Empty {},
+            use_accesses: Empty {},
+            package_interfaces: Empty {},
+            sub_packages: Empty {},
+        }
+        .serialize(serializer)
+    }
+}
+
+impl IntoXmlRoot for NewPackage<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("pak".into(), "http://www.sap.com/adt/packages".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_package() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <pak:package pak:isEncapsulated="false" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:name="SADT_TOOLS_CORE" adtcore:type="DEVC/K" adtcore:changedAt="2023-03-08T00:00:00Z" adtcore:version="active" adtcore:createdAt="2009-11-26T00:00:00Z" adtcore:changedBy="SAP" adtcore:createdBy="SAP" adtcore:description="ADT Tools Core" adtcore:descriptionTextLimit="60" adtcore:language="EN" xmlns:pak="http://www.sap.com/adt/packages" xmlns:adtcore="http://www.sap.com/adt/core">
+                <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="/sap/bc/adt/vit/wb/object_type/devck/object_name/SADT_TOOLS_CORE" rel="self" type="application/vnd.sap.sapgui" title="Representation in SAP Gui"/>
+                <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/sadt_tools_core" adtcore:type="DEVC/K" adtcore:name="SADT_TOOLS_CORE"/>
+                <pak:attributes pak:packageType="development" pak:isPackageTypeEditable="false" pak:isAddingObjectsAllowed="true" pak:isAddingObjectsAllowedEditable="true" pak:isEncapsulated="false" pak:isEncapsulationEditable="false" pak:recordChanges="true" pak:isRecordChangesEditable="false" pak:isSwitchVisible="false"/>
+                <pak:superPackage adtcore:uri="/sap/bc/adt/packages/sadt_main" adtcore:type="DEVC/K" adtcore:name="SADT_MAIN" adtcore:description="ABAP Development Tools"/>
+                <pak:applicationComponent pak:name="BC-DWB-AIE" pak:description="Installation and Infrastructure for ABAP Tools in Eclipse" pak:isVisible="true" pak:isEditable="false"/>
+                <pak:transport>
+                    <pak:softwareComponent pak:name="SAP_BASIS" pak:description="SAP Basis Component" pak:isVisible="true" pak:isEditable="false"/>
+                    <pak:transportLayer pak:name="SAP" pak:description="" pak:isVisible="true" pak:isEditable="false"/>
+                </pak:transport>
+                <pak:useAccesses pak:isVisible="true"/>
+                <pak:packageInterfaces pak:isVisible="true"/>
+                <pak:subPackages>
+                    <pak:packageRef adtcore:uri="/sap/bc/adt/packages/sadt_tools_core_test" adtcore:type="DEVC/K" adtcore:name="SADT_TOOLS_CORE_TEST"/>
+                </pak:subPackages>
+            </pak:package>"#;
+
+        let result: Package = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.name, "SADT_TOOLS_CORE");
+        assert_eq!(result.attributes.package_type, PackageType::Development);
+        assert_eq!(
+            result.super_package.and_then(|p| p.name).as_deref(),
+            Some("SADT_MAIN")
+        );
+        assert_eq!(
+            result
+                .transport
+                .and_then(|t| t.software_component)
+                .map(|c| c.name)
+                .as_deref(),
+            Some("SAP_BASIS")
+        );
+        assert_eq!(result.sub_packages.packages[0].name, "SADT_TOOLS_CORE_TEST");
+    }
+
+    #[test]
+    fn deserialize_top_level_local_package() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <pak:package adtcore:name="$TMP" adtcore:type="DEVC/K" adtcore:description="Local Objects" xmlns:pak="http://www.sap.com/adt/packages" xmlns:adtcore="http://www.sap.com/adt/core">
+                <pak:attributes pak:packageType="development"/>
+                <pak:superPackage/>
+                <pak:transport>
+                    <pak:softwareComponent pak:name="LOCAL"/>
+                    <pak:transportLayer pak:name=""/>
+                </pak:transport>
+                <pak:subPackages/>
+            </pak:package>"#;
+
+        let result: Package = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.super_package.unwrap().name.is_none());
+        assert!(result.sub_packages.packages.is_empty());
+    }
+
+    #[test]
+    fn serialize_new_package() {
+        let package = NewPackageBuilder::default()
+            .name("ZMY_PACKAGE")
+            .description("My Package")
+            .super_package("ZMY_PARENT")
+            .software_component("HOME")
+            .transport_layer("ZA4H")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            package.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <pak:package xmlns:adtcore=\"http://www.sap.com/adt/core\" xmlns:pak=\"http://www.sap.com/adt/packages\" \
+                adtcore:name=\"ZMY_PACKAGE\" adtcore:type=\"DEVC/K\" adtcore:description=\"My Package\">\
+                <adtcore:packageRef adtcore:name=\"ZMY_PACKAGE\" />\
+                <pak:attributes pak:packageType=\"development\" />\
+                <pak:superPackage adtcore:name=\"ZMY_PARENT\" />\
+                <pak:applicationComponent pak:name=\"\" />\
+                <pak:transport>\
+                    <pak:softwareComponent pak:name=\"HOME\" />\
+                    <pak:transportLayer pak:name=\"ZA4H\" />\
+                </pak:transport>\
+                <pak:translation />\
+                <pak:useAccesses />\
+                <pak:packageInterfaces />\
+                <pak:subPackages />\
+            </pak:package>"
+        );
+    }
+}
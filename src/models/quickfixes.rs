@@ -0,0 +1,192 @@
+/// Quick Fixes - http://www.sap.com/adt/quickfixes
+///
+/// Proposals are evaluated at a position of the source code, applying one of them yields
+/// the text deltas to change the source code with.
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::models::{
+    abapsource::SourceLocation,
+    adtcore::{ObjectReference, UriReference},
+    serialize::IntoXmlRoot,
+};
+
+/// A quick fix that can be applied at the evaluated position.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "qf:evaluationResult")]
+#[readonly::make]
+pub struct Proposal {
+    /// The reference to the proposal, the name is the title of the fix, e.g. `Declare variable`
+    #[serde(rename = "adtcore:objectReference")]
+    pub reference: ObjectReference,
+
+    /// Server specific data of the proposal, which has to be sent back when applying it.
+    #[serde(rename = "qf:userContent", default)]
+    pub user_content: String,
+}
+
+impl Proposal {
+    /// The title of the fix, e.g. `Declare local variable lv_counter`
+    pub fn title(&self) -> &str {
+        &self.reference.name
+    }
+
+    /// The URI the proposal is applied through.
+    pub fn uri(&self) -> &str {
+        &self.reference.uri
+    }
+}
+
+/// The quick fixes available at a position.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "qf:evaluationResults")]
+#[readonly::make]
+pub struct Proposals {
+    #[serde(rename = "qf:evaluationResult", default)]
+    pub proposals: Vec<Proposal>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProposalInput<'a> {
+    #[serde(rename = "content")]
+    content: &'a str,
+
+    #[serde(rename = "adtcore:objectReference")]
+    reference: UriReference<'a>,
+}
+
+/// The request to apply a proposal to the current source code.
+#[derive(Debug, Serialize)]
+#[serde(rename = "qf:proposalRequest")]
+pub(crate) struct ProposalRequest<'a> {
+    #[serde(rename = "input")]
+    input: ProposalInput<'a>,
+
+    #[serde(rename = "userContent")]
+    user_content: &'a str,
+}
+
+impl<'a> ProposalRequest<'a> {
+    pub(crate) fn new(proposal: &'a Proposal, location: &'a str, source: &'a str) -> Self {
+        Self {
+            input: ProposalInput {
+                content: source,
+                reference: UriReference::new(location),
+            },
+            user_content: &proposal.user_content,
+        }
+    }
+}
+
+impl IntoXmlRoot for ProposalRequest<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+            ("qf".into(), "http://www.sap.com/adt/quickfixes".into()),
+        ]
+    }
+}
+
+/// A change of the source code, replacing the range of the reference with the content.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "unit")]
+#[readonly::make]
+pub struct Delta {
+    /// The replaced range, e.g. `…/zwegwerf1/source/main#start=3,0;end=3,0`
+    #[serde(rename = "adtcore:objectReference")]
+    pub reference: ObjectReference,
+
+    /// The text the range is replaced with.
+    #[serde(rename = "content", default)]
+    pub content: String,
+}
+
+impl Delta {
+    /// The replaced range, an insertion if start and end are equal.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.reference.location()
+    }
+}
+
+/// Wraps the deltas of an applied proposal.
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct Deltas {
+    #[serde(rename = "unit", default)]
+    pub deltas: Vec<Delta>,
+}
+
+/// The result of applying a proposal, i.e. the changes to make to the source code.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "qf:proposalResult")]
+#[readonly::make]
+pub struct ProposalResult {
+    #[serde(rename = "deltas", default)]
+    pub deltas: Deltas,
+}
+
+impl ProposalResult {
+    /// Returns the deltas in the order they are to be applied, i.e. from the end of the source
+    /// to its start, so the positions of the remaining deltas stay valid.
+    pub fn deltas(&self) -> Vec<&Delta> {
+        let mut deltas: Vec<_> = self.deltas.deltas.iter().collect();
+        deltas.sort_by_key(|delta| std::cmp::Reverse(delta.location().map(|l| l.start)));
+        deltas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::abapsource::Position;
+
+    #[test]
+    fn deserialize_proposals() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <qf:evaluationResults xmlns:qf="http://www.sap.com/adt/quickfixes" xmlns:adtcore="http://www.sap.com/adt/core">
+                <qf:evaluationResult>
+                    <adtcore:objectReference adtcore:uri="/sap/bc/adt/quickfixes/abap/declare_local_variable" adtcore:type="quickfixes_proposal" adtcore:name="Declare local variable lv_total" adtcore:description="Declares the variable"/>
+                    <qf:userContent>&lt;variable&gt;LV_TOTAL&lt;/variable&gt;</qf:userContent>
+                </qf:evaluationResult>
+            </qf:evaluationResults>"#;
+
+        let result: Proposals = serde_xml_rs::from_str(plain).unwrap();
+        let proposal = &result.proposals[0];
+        assert_eq!(proposal.title(), "Declare local variable lv_total");
+        assert_eq!(proposal.user_content, "<variable>LV_TOTAL</variable>");
+
+        let request = ProposalRequest::new(
+            proposal,
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=3,0",
+            "lv_total = 1.",
+        );
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><qf:proposalRequest xmlns:adtcore="http://www.sap.com/adt/core" xmlns:qf="http://www.sap.com/adt/quickfixes"><input><content>lv_total = 1.</content><adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=3,0" /></input><userContent>&lt;variable&gt;LV_TOTAL&lt;/variable&gt;</userContent></qf:proposalRequest>"#
+        );
+    }
+
+    #[test]
+    fn deltas_are_applied_from_the_end() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <qf:proposalResult xmlns:qf="http://www.sap.com/adt/quickfixes" xmlns:adtcore="http://www.sap.com/adt/core">
+                <deltas>
+                    <unit>
+                        <adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=2,0;end=2,0" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1"/>
+                        <content>DATA lv_total TYPE i.
+</content>
+                    </unit>
+                    <unit>
+                        <adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=5,0;end=5,8" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1"/>
+                        <content>lv_total</content>
+                    </unit>
+                </deltas>
+            </qf:proposalResult>"#;
+
+        let result: ProposalResult = serde_xml_rs::from_str(plain).unwrap();
+        let deltas = result.deltas();
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].location().unwrap().end, Some(Position::new(5, 8)));
+        assert!(deltas[1].content.starts_with("DATA lv_total"));
+    }
+}
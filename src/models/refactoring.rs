@@ -0,0 +1,444 @@
+/// Refactoring - http://www.sap.com/adt/refactoring/genericrefactoring
+///
+/// Refactorings are run in three steps: the server evaluates the refactoring at a position,
+/// previews the changes with the input of the user, e.g. the new name, and executes them.
+/// Every step receives the result of the previous one, which contains the [`ChangeSet`].
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::borrow::Cow;
+
+use crate::models::{
+    abapsource::{Position, SourceLocation},
+    serialize::IntoXmlRoot,
+};
+
+/// A refactoring that can be previewed and executed, see [`crate::api::refactoring`]
+pub trait Refactoring: Serialize + DeserializeOwned + IntoXmlRoot + Send + Sync {
+    /// The relation of the refactoring, e.g. `http://www.sap.com/adt/relations/refactoring/rename`
+    const RELATION: &'static str;
+
+    /// The endpoint the steps of the refactoring are posted to.
+    const ENDPOINT: &'static str = "refactorings";
+
+    /// The changes of the refactoring.
+    fn change_set(&self) -> &ChangeSet;
+
+    /// The changes of the refactoring, e.g. to provide the transport before the execution.
+    fn change_set_mut(&mut self) -> &mut ChangeSet;
+}
+
+fn namespaces(
+    prefix: &'static str,
+    namespace: &'static str,
+) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+    vec![
+        ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        (
+            "generic".into(),
+            "http://www.sap.com/adt/refactoring/genericrefactoring".into(),
+        ),
+        (prefix.into(), namespace.into()),
+    ]
+}
+
+/// A replacement of text in the source code of an affected object.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "generic:textReplaceDelta")]
+#[readonly::make]
+pub struct TextReplaceDelta {
+    /// The replaced range as URI fragment, e.g. `#start=3,0;end=3,10`
+    #[serde(rename = "generic:rangeFragment")]
+    pub range_fragment: String,
+
+    /// The text that is replaced.
+    #[serde(rename = "generic:contentOld", default)]
+    pub old_content: String,
+
+    /// The text it is replaced with.
+    #[serde(rename = "generic:contentNew", default)]
+    pub new_content: String,
+}
+
+impl TextReplaceDelta {
+    /// The start and end of the replaced range.
+    pub fn range(&self) -> Option<(Position, Position)> {
+        let location: SourceLocation = self.range_fragment.parse().ok()?;
+        Some((location.start, location.end.unwrap_or(location.start)))
+    }
+}
+
+/// Wraps the text replacements of an affected object.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[readonly::make]
+pub struct TextReplaceDeltas {
+    #[serde(rename = "generic:textReplaceDelta", default)]
+    pub deltas: Vec<TextReplaceDelta>,
+}
+
+/// An object that is changed by the refactoring.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "generic:affectedObject")]
+#[readonly::make]
+pub struct AffectedObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/oo/classes/zcl_demo/source/main`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The type of the object, e.g. `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The name of the object, e.g. `ZCL_DEMO`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The text replacements in the source code of the object, filled by the preview.
+    #[serde(rename = "generic:textReplaceDeltas", default)]
+    pub deltas: TextReplaceDeltas,
+}
+
+impl AffectedObject {
+    /// Returns the replaced ranges along with the replacements.
+    pub fn edits(&self) -> impl Iterator<Item = (SourceLocation, &TextReplaceDelta)> {
+        self.deltas.deltas.iter().filter_map(|delta| {
+            let (start, end) = delta.range()?;
+            Some((SourceLocation::new(&self.uri, start, Some(end)), delta))
+        })
+    }
+}
+
+/// Wraps the objects that are changed by the refactoring.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[readonly::make]
+pub struct AffectedObjects {
+    #[serde(rename = "generic:affectedObject", default)]
+    pub objects: Vec<AffectedObject>,
+}
+
+/// The changes of a refactoring, along with the transport they are recorded in.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "generic:genericRefactoring")]
+#[readonly::make]
+pub struct ChangeSet {
+    /// The title of the refactoring, e.g. `Rename Data Object`
+    #[serde(rename = "generic:title", default)]
+    pub title: String,
+
+    /// The position the refactoring was started at.
+    #[serde(rename = "generic:adtObjectUri", default)]
+    pub object_uri: String,
+
+    /// The objects that are changed.
+    #[serde(rename = "generic:affectedObjects", default)]
+    pub affected_objects: AffectedObjects,
+
+    /// The transport the changes are recorded in, required unless all objects are local.
+    #[serde(rename = "generic:transport", default)]
+    pub transport: String,
+
+    /// Whether the refactoring may be executed despite syntax errors.
+    #[serde(rename = "generic:ignoreSyntaxErrorsAllowed", default)]
+    pub ignore_syntax_errors_allowed: bool,
+
+    /// Whether syntax errors are ignored during the execution.
+    #[serde(rename = "generic:ignoreSyntaxErrors", default)]
+    pub ignore_syntax_errors: bool,
+}
+
+impl ChangeSet {
+    /// The request to evaluate a refactoring at a position or of an object.
+    pub(crate) fn evaluation(uri: impl Into<String>) -> Self {
+        Self {
+            title: String::new(),
+            object_uri: uri.into(),
+            affected_objects: AffectedObjects::default(),
+            transport: String::new(),
+            ignore_syntax_errors_allowed: false,
+            ignore_syntax_errors: false,
+        }
+    }
+
+    /// Returns the objects that are changed.
+    pub fn objects(&self) -> impl Iterator<Item = &AffectedObject> {
+        self.affected_objects.objects.iter()
+    }
+
+    /// Returns all text replacements of all objects.
+    pub fn edits(&self) -> impl Iterator<Item = (SourceLocation, &TextReplaceDelta)> {
+        self.objects().flat_map(AffectedObject::edits)
+    }
+
+    /// Sets the transport the changes are recorded in.
+    pub fn set_transport(&mut self, transport: impl Into<String>) {
+        self.transport = transport.into();
+    }
+
+    /// Ignores syntax errors during the execution, if the refactoring allows it.
+    pub fn set_ignore_syntax_errors(&mut self, ignore: bool) {
+        self.ignore_syntax_errors = ignore && self.ignore_syntax_errors_allowed;
+    }
+}
+
+impl IntoXmlRoot for ChangeSet {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+            (
+                "generic".into(),
+                "http://www.sap.com/adt/refactoring/genericrefactoring".into(),
+            ),
+        ]
+    }
+}
+
+/// Renames an identifier or object, e.g. a variable, a method or a class.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "rename:renameRefactoring")]
+#[readonly::make]
+pub struct Rename {
+    /// The current name.
+    #[serde(rename = "rename:oldName", default)]
+    pub old_name: String,
+
+    /// The new name, to be provided before the preview.
+    #[serde(rename = "rename:newName", default)]
+    pub new_name: String,
+
+    #[serde(rename = "generic:genericRefactoring")]
+    pub changes: ChangeSet,
+}
+
+impl Rename {
+    /// Sets the new name, which is applied to all occurrences.
+    pub fn set_new_name(&mut self, name: impl Into<String>) {
+        self.new_name = name.into();
+    }
+}
+
+impl Refactoring for Rename {
+    const RELATION: &'static str = "http://www.sap.com/adt/relations/refactoring/rename";
+
+    fn change_set(&self) -> &ChangeSet {
+        &self.changes
+    }
+
+    fn change_set_mut(&mut self) -> &mut ChangeSet {
+        &mut self.changes
+    }
+}
+
+impl IntoXmlRoot for Rename {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        namespaces("rename", "http://www.sap.com/adt/ris/refactoring/rename")
+    }
+}
+
+/// A parameter of an extracted method, proposed by the server.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "extractmethod:parameter")]
+#[readonly::make]
+pub struct MethodParameter {
+    /// The name of the parameter.
+    #[serde(rename = "extractmethod:name")]
+    pub name: String,
+
+    /// The direction of the parameter, e.g. `IMPORTING` or `RETURNING`
+    #[serde(rename = "extractmethod:direction", default)]
+    pub direction: String,
+
+    /// Whether the parameter is passed by value.
+    #[serde(rename = "extractmethod:byValue", default)]
+    pub by_value: bool,
+
+    /// The type of the parameter, e.g. `I`
+    #[serde(rename = "extractmethod:type", default)]
+    pub type_name: String,
+}
+
+/// Wraps the parameters of an extracted method.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[readonly::make]
+pub struct MethodParameters {
+    #[serde(rename = "extractmethod:parameter", default)]
+    pub parameters: Vec<MethodParameter>,
+}
+
+/// Extracts the statements of a range into a new method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "extractmethod:extractMethodRefactoring")]
+#[readonly::make]
+pub struct ExtractMethod {
+    /// The name of the new method, to be provided before the preview.
+    #[serde(rename = "extractmethod:name", default)]
+    pub name: String,
+
+    /// The visibility of the new method, e.g. `PRIVATE`
+    #[serde(rename = "extractmethod:visibility", default)]
+    pub visibility: String,
+
+    /// Whether the new method is a static method.
+    #[serde(rename = "extractmethod:isStatic", default)]
+    pub is_static: bool,
+
+    /// The parameters of the new method, derived from the extracted statements.
+    #[serde(rename = "extractmethod:parameters", default)]
+    pub parameters: MethodParameters,
+
+    #[serde(rename = "generic:genericRefactoring")]
+    pub changes: ChangeSet,
+}
+
+impl ExtractMethod {
+    /// Sets the name of the new method.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+
+    /// Sets the visibility of the new method, e.g. `PUBLIC`
+    pub fn set_visibility(&mut self, visibility: impl Into<String>) {
+        self.visibility = visibility.into();
+    }
+}
+
+impl Refactoring for ExtractMethod {
+    const RELATION: &'static str = "http://www.sap.com/adt/relations/refactoring/extractmethod";
+
+    fn change_set(&self) -> &ChangeSet {
+        &self.changes
+    }
+
+    fn change_set_mut(&mut self) -> &mut ChangeSet {
+        &mut self.changes
+    }
+}
+
+impl IntoXmlRoot for ExtractMethod {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        namespaces(
+            "extractmethod",
+            "http://www.sap.com/adt/refactoring/extractmethodrefactoring",
+        )
+    }
+}
+
+/// Moves objects to another package, recording the change in a transport.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename = "changepackage:changePackageRefactoring")]
+#[readonly::make]
+pub struct ChangePackage {
+    /// The package the objects are currently assigned to.
+    #[serde(rename = "changepackage:oldPackage", default)]
+    pub old_package: String,
+
+    /// The package the objects are moved to, to be provided before the preview.
+    #[serde(rename = "changepackage:newPackage", default)]
+    pub new_package: String,
+
+    #[serde(rename = "generic:genericRefactoring")]
+    pub changes: ChangeSet,
+}
+
+impl ChangePackage {
+    /// Sets the package the objects are moved to.
+    pub fn set_new_package(&mut self, package: impl Into<String>) {
+        self.new_package = package.into();
+    }
+}
+
+impl Refactoring for ChangePackage {
+    const RELATION: &'static str = "http://www.sap.com/adt/relations/refactoring/changepackage";
+    const ENDPOINT: &'static str = "refactoring/changepackage";
+
+    fn change_set(&self) -> &ChangeSet {
+        &self.changes
+    }
+
+    fn change_set_mut(&mut self) -> &mut ChangeSet {
+        &mut self.changes
+    }
+}
+
+impl IntoXmlRoot for ChangePackage {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        namespaces(
+            "changepackage",
+            "http://www.sap.com/adt/refactoring/changepackagerefactoring",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RENAME_PREVIEW: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <rename:renameRefactoring xmlns:rename="http://www.sap.com/adt/ris/refactoring/rename" xmlns:generic="http://www.sap.com/adt/refactoring/genericrefactoring" xmlns:adtcore="http://www.sap.com/adt/core">
+            <rename:oldName>LV_COUNTER</rename:oldName>
+            <rename:newName>LV_TOTAL</rename:newName>
+            <generic:genericRefactoring>
+                <generic:title>Rename Data Object</generic:title>
+                <generic:adtObjectUri>/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=2,5</generic:adtObjectUri>
+                <generic:affectedObjects>
+                    <generic:affectedObject adtcore:uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main" adtcore:type="PROG/P" adtcore:name="ZWEGWERF1">
+                        <generic:textReplaceDeltas>
+                            <generic:textReplaceDelta>
+                                <generic:rangeFragment>#start=2,5;end=2,15</generic:rangeFragment>
+                                <generic:contentOld>lv_counter</generic:contentOld>
+                                <generic:contentNew>lv_total</generic:contentNew>
+                            </generic:textReplaceDelta>
+                            <generic:textReplaceDelta>
+                                <generic:rangeFragment>#start=3,0;end=3,10</generic:rangeFragment>
+                                <generic:contentOld>lv_counter</generic:contentOld>
+                                <generic:contentNew>lv_total</generic:contentNew>
+                            </generic:textReplaceDelta>
+                        </generic:textReplaceDeltas>
+                    </generic:affectedObject>
+                </generic:affectedObjects>
+                <generic:transport/>
+                <generic:ignoreSyntaxErrorsAllowed>true</generic:ignoreSyntaxErrorsAllowed>
+                <generic:ignoreSyntaxErrors>false</generic:ignoreSyntaxErrors>
+            </generic:genericRefactoring>
+        </rename:renameRefactoring>"#;
+
+    #[test]
+    fn deserialize_rename_preview() {
+        let rename: Rename = serde_xml_rs::from_str(RENAME_PREVIEW).unwrap();
+        assert_eq!(rename.old_name, "LV_COUNTER");
+        assert_eq!(rename.change_set().objects().count(), 1);
+
+        let edits: Vec<_> = rename.change_set().edits().collect();
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[1].0.start, Position::new(3, 0));
+        assert_eq!(edits[1].0.end, Some(Position::new(3, 10)));
+        assert_eq!(edits[1].1.new_content, "lv_total");
+    }
+
+    #[test]
+    fn preview_is_sent_back_with_transport() {
+        let mut rename: Rename = serde_xml_rs::from_str(RENAME_PREVIEW).unwrap();
+        rename.change_set_mut().set_transport("A4HK900089");
+        rename.change_set_mut().set_ignore_syntax_errors(true);
+
+        let xml = rename.into_xml_root().unwrap();
+        let rename: Rename = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(rename.new_name, "LV_TOTAL");
+        assert_eq!(rename.changes.transport, "A4HK900089");
+        assert!(rename.changes.ignore_syntax_errors);
+        assert_eq!(rename.change_set().edits().count(), 2);
+    }
+
+    #[test]
+    fn serialize_evaluation() {
+        let location = SourceLocation::new(
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+            Position::new(2, 5),
+            None,
+        );
+
+        assert_eq!(
+            ChangeSet::evaluation(location.to_string())
+                .into_xml_root()
+                .unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><generic:genericRefactoring xmlns:adtcore="http://www.sap.com/adt/core" xmlns:generic="http://www.sap.com/adt/refactoring/genericrefactoring"><generic:title></generic:title><generic:adtObjectUri>/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=2,5</generic:adtObjectUri><generic:affectedObjects /><generic:transport></generic:transport><generic:ignoreSyntaxErrorsAllowed>false</generic:ignoreSyntaxErrorsAllowed><generic:ignoreSyntaxErrors>false</generic:ignoreSyntaxErrors></generic:genericRefactoring>"#
+        );
+    }
+}
@@ -1,34 +1,82 @@
 /// Transport Properties (TPR) - http://www.sap.com/adt/ris/transportProperties
-use crate::models::atom;
+use crate::{ParamValue, models::atom};
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+use std::{borrow::Cow, str::FromStr};
 
 /// Represents the status of a Transport in the SAP System
 ///
 /// Refer to domain `SCTS_REQ/TRSTATUS` in SAP System.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TransportStatus {
     /// The transport is modifiable, objects may be added - default state.
-    #[serde(rename = "D")]
     Modifiable,
     /// Ensures that only the owner of the transport can add more users.
     /// See [Protecting Transport Request](https://help.sap.com/docs/abap-cloud/abap-development-tools-user-guide/protecting-transport-request?locale=en-US)
-    #[serde(rename = "L")]
     ProtectedModifiable,
     /// The release of the transport has started, details to be clarified.
-    #[serde(rename = "O")]
     ReleaseStarted,
     /// The transport has been released.
-    #[serde(rename = "R")]
     Released,
     /// The transport has been released but with import protection, to be clarified.
-    #[serde(rename = "N")]
     ReleasedWithImportProtection,
     /// The transport is in preparation for release, to be clarified.
-    #[serde(rename = "P")]
     ReleasePreparation,
 }
 
+impl TransportStatus {
+    /// The status as stored in the system, e.g. `D` for [`TransportStatus::Modifiable`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Modifiable => "D",
+            Self::ProtectedModifiable => "L",
+            Self::ReleaseStarted => "O",
+            Self::Released => "R",
+            Self::ReleasedWithImportProtection => "N",
+            Self::ReleasePreparation => "P",
+        }
+    }
+
+    /// Whether objects can still be added to the transport.
+    pub fn is_modifiable(&self) -> bool {
+        matches!(self, Self::Modifiable | Self::ProtectedModifiable)
+    }
+}
+
+impl FromStr for TransportStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "D" => Ok(Self::Modifiable),
+            "L" => Ok(Self::ProtectedModifiable),
+            "O" => Ok(Self::ReleaseStarted),
+            "R" => Ok(Self::Released),
+            "N" => Ok(Self::ReleasedWithImportProtection),
+            "P" => Ok(Self::ReleasePreparation),
+            other => Err(format!("unknown transport status '{other}'")),
+        }
+    }
+}
+
+// Deserialized from the plain value, as the status is provided as attribute or element text.
+impl<'de> Deserialize<'de> for TransportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl<'a> ParamValue<'a> for TransportStatus {
+    fn as_str(&self) -> Cow<'a, str> {
+        Cow::Borrowed(TransportStatus::as_str(self))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename = "tpr:transportProperties")]
 #[readonly::make]
@@ -0,0 +1,494 @@
+/// Usage References (Where-Used) - http://www.sap.com/adt/ris/usageReferences
+///
+/// Provides the objects referencing a given object as well as snippets of the code
+/// locations in which the references occur.
+use crate::models::{abapsource::SourceLocation, adtcore, serialize::IntoXmlRoot};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+const NAMESPACE: &str = "http://www.sap.com/adt/ris/usageReferences";
+
+/// An object type the where-used search is restricted to.
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:type")]
+struct ScopeObjectType<'a> {
+    #[serde(rename = "@name")]
+    name: &'a Cow<'a, str>,
+
+    #[serde(rename = "@isSelected")]
+    is_selected: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:objectTypes")]
+struct ScopeObjectTypes<'a> {
+    #[serde(rename = "usageReferences:type")]
+    types: Vec<ScopeObjectType<'a>>,
+}
+
+/// A package the where-used search is restricted to.
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:package")]
+struct ScopePackage<'a> {
+    #[serde(rename = "@name")]
+    name: &'a Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:packages")]
+struct ScopePackages<'a> {
+    #[serde(rename = "usageReferences:package")]
+    packages: Vec<ScopePackage<'a>>,
+}
+
+/// Restricts the objects considered in the where-used search, only objects matching
+/// one of the object types and one of the packages are returned.
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:scope")]
+struct UsageScope<'a> {
+    #[serde(
+        rename = "usageReferences:objectTypes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    object_types: Option<ScopeObjectTypes<'a>>,
+
+    #[serde(
+        rename = "usageReferences:packages",
+        skip_serializing_if = "Option::is_none"
+    )]
+    packages: Option<ScopePackages<'a>>,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename = "usageReferences:affectedObjects")]
+struct AffectedObjects<'a> {
+    #[serde(rename = "usageReferences:affectedObject")]
+    objects: Vec<AffectedObject<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:affectedObject")]
+struct AffectedObject<'a> {
+    #[serde(rename = "@uri")]
+    uri: &'a Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:usageReferenceRequest")]
+pub(crate) struct UsageReferenceRequest<'a> {
+    #[serde(
+        rename = "usageReferences:scope",
+        skip_serializing_if = "Option::is_none"
+    )]
+    scope: Option<UsageScope<'a>>,
+
+    #[serde(rename = "usageReferences:affectedObjects")]
+    affected_objects: AffectedObjects<'a>,
+}
+
+impl<'a> UsageReferenceRequest<'a> {
+    pub(crate) fn new(object_types: &'a [Cow<'a, str>], packages: &'a [Cow<'a, str>]) -> Self {
+        let object_types = (!object_types.is_empty()).then(|| ScopeObjectTypes {
+            types: object_types
+                .iter()
+                .map(|name| ScopeObjectType {
+                    name,
+                    is_selected: true,
+                })
+                .collect(),
+        });
+        let packages = (!packages.is_empty()).then(|| ScopePackages {
+            packages: packages.iter().map(|name| ScopePackage { name }).collect(),
+        });
+
+        let scope = (object_types.is_some() || packages.is_some()).then_some(UsageScope {
+            object_types,
+            packages,
+        });
+        Self {
+            scope,
+            affected_objects: AffectedObjects::default(),
+        }
+    }
+}
+
+impl IntoXmlRoot for UsageReferenceRequest<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("usageReferences".into(), NAMESPACE.into())]
+    }
+}
+
+/// The result of a where-used search.
+///
+/// The server delivers the referencing objects as a flat list that describes a tree, the
+/// hierarchy (e.g. package > class > method) is encoded through [`ReferencedObject::parent_uri`].
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:usageReferenceResult")]
+#[readonly::make]
+pub struct UsageReferenceResult {
+    /// The number of objects that reference the object.
+    #[serde(rename = "@numberOfResults")]
+    pub number_of_results: i32,
+
+    /// Description of the result, e.g. `Where-used list for ZCL_FOO`
+    #[serde(rename = "@resultDescription")]
+    pub description: Option<String>,
+
+    /// Identifier of the object the search was done for, required for [`crate::api::repository::UsageSnippets`].
+    #[serde(rename = "@referencedObjectIdentifier")]
+    pub object_identifier: Option<String>,
+
+    #[serde(rename = "usageReferences:referencedObjects", default)]
+    pub referenced_objects: ReferencedObjects,
+}
+
+impl UsageReferenceResult {
+    /// The objects in which the references actually occur, excluding the grouping nodes.
+    pub fn results(&self) -> impl Iterator<Item = &ReferencedObject> {
+        self.referenced_objects
+            .objects
+            .iter()
+            .filter(|o| o.is_result)
+    }
+
+    /// The top level objects of the hierarchy, usually packages.
+    pub fn roots(&self) -> impl Iterator<Item = &ReferencedObject> {
+        self.referenced_objects
+            .objects
+            .iter()
+            .filter(|o| o.parent_uri.is_none())
+    }
+
+    /// The objects directly below the object with the given uri in the hierarchy.
+    pub fn children<'a>(&'a self, uri: &'a str) -> impl Iterator<Item = &'a ReferencedObject> {
+        self.referenced_objects
+            .objects
+            .iter()
+            .filter(move |o| o.parent_uri.as_deref() == Some(uri))
+    }
+}
+
+/// Wraps a collection of [`ReferencedObject`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "usageReferences:referencedObjects")]
+#[readonly::make]
+pub struct ReferencedObjects {
+    #[serde(rename = "usageReferences:referencedObject", default)]
+    pub objects: Vec<ReferencedObject>,
+}
+
+/// An object that references the searched object, or a node grouping such objects.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:referencedObject")]
+#[readonly::make]
+pub struct ReferencedObject {
+    /// The URI of the object, e.g. `/sap/bc/adt/oo/classes/zcl_foo/source/main`
+    #[serde(rename = "@uri")]
+    pub uri: String,
+
+    /// The URI of the parent object in the hierarchy, not present for the top level.
+    #[serde(rename = "@parentUri")]
+    pub parent_uri: Option<String>,
+
+    /// Whether the reference occurs in this object, `false` for grouping nodes.
+    #[serde(rename = "@isResult", default)]
+    pub is_result: bool,
+
+    /// Whether there are other objects below this one in the hierarchy.
+    #[serde(rename = "@canHaveChildren", default)]
+    pub can_have_children: bool,
+
+    /// Comma seperated information about the kind of usage, e.g. `gradeDirect,includeProductive`
+    #[serde(rename = "@usageInformation")]
+    pub usage_information: Option<String>,
+
+    /// Identifier of the referencing object, required for [`crate::api::repository::UsageSnippets`].
+    #[serde(rename = "@objectIdentifier")]
+    pub object_identifier: Option<String>,
+
+    /// Details of the referencing object such as its name and type.
+    #[serde(rename = "usageReferences:adtObject")]
+    pub object: ReferencingObject,
+}
+
+/// Descriptive data of a [`ReferencedObject`].
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:adtObject")]
+#[readonly::make]
+pub struct ReferencingObject {
+    /// The name of the object, e.g. `ZCL_FOO`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The type of the object, e.g. `CLAS/OC` or `DEVC/K`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The short description of the object
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+
+    /// The user responsible for the object
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: Option<String>,
+
+    /// The package the object is assigned to, not provided for packages themselves.
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: Option<adtcore::PackageRef>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:objectIdentifier")]
+struct ObjectIdentifier<'a> {
+    #[serde(rename = "@optional")]
+    optional: bool,
+
+    #[serde(rename = "#text")]
+    identifier: &'a Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:objectIdentifiers")]
+struct ObjectIdentifiers<'a> {
+    #[serde(rename = "usageReferences:objectIdentifier")]
+    identifiers: Vec<ObjectIdentifier<'a>>,
+}
+
+/// A referencing object to get the code snippets of, taken from a [`ReferencedObject`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetObject<'a> {
+    identifier: Cow<'a, str>,
+    uri: Cow<'a, str>,
+}
+
+impl<'a> SnippetObject<'a> {
+    /// Pairs the identifier of a referencing object, e.g. `ABAPFullName;ZCL_FOO;`, with its URI.
+    pub fn new(identifier: impl Into<Cow<'a, str>>, uri: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            uri: uri.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "usageReferences:usageSnippetRequest")]
+pub(crate) struct UsageSnippetRequest<'a> {
+    #[serde(rename = "usageReferences:objectIdentifiers")]
+    identifiers: ObjectIdentifiers<'a>,
+
+    #[serde(rename = "usageReferences:affectedObjects")]
+    affected_objects: AffectedObjects<'a>,
+}
+
+impl<'a> UsageSnippetRequest<'a> {
+    pub(crate) fn new(objects: &'a [SnippetObject<'a>]) -> Self {
+        Self {
+            identifiers: ObjectIdentifiers {
+                identifiers: objects
+                    .iter()
+                    .map(|object| ObjectIdentifier {
+                        optional: false,
+                        identifier: &object.identifier,
+                    })
+                    .collect(),
+            },
+            affected_objects: AffectedObjects {
+                objects: objects
+                    .iter()
+                    .map(|object| AffectedObject { uri: &object.uri })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl IntoXmlRoot for UsageSnippetRequest<'_> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("usageReferences".into(), NAMESPACE.into())]
+    }
+}
+
+/// The code snippets in which the references of a where-used search occur.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:usageSnippetResult")]
+#[readonly::make]
+pub struct UsageSnippetResult {
+    #[serde(rename = "usageReferences:codeSnippetObjects", default)]
+    pub snippet_objects: CodeSnippetObjects,
+}
+
+/// Wraps a collection of [`CodeSnippetObject`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "usageReferences:codeSnippetObjects")]
+#[readonly::make]
+pub struct CodeSnippetObjects {
+    #[serde(rename = "usageReferences:codeSnippetObject", default)]
+    pub objects: Vec<CodeSnippetObject>,
+}
+
+/// The snippets of a single referencing object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:codeSnippetObject")]
+#[readonly::make]
+pub struct CodeSnippetObject {
+    /// Identifier of the referencing object the snippets belong to.
+    #[serde(rename = "usageReferences:objectIdentifier")]
+    pub object_identifier: String,
+
+    #[serde(rename = "usageReferences:codeSnippets", default)]
+    pub snippets: CodeSnippets,
+}
+
+/// Wraps a collection of [`CodeSnippet`]s
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename = "usageReferences:codeSnippets")]
+#[readonly::make]
+pub struct CodeSnippets {
+    #[serde(rename = "usageReferences:codeSnippet", default)]
+    pub snippets: Vec<CodeSnippet>,
+}
+
+/// A line of code in which a reference occurs.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:codeSnippet")]
+#[readonly::make]
+pub struct CodeSnippet {
+    /// Where in the source of the referencing object the reference occurs.
+    #[serde(rename = "@uri")]
+    pub location: SourceLocation,
+
+    /// The text that matched the searched object, e.g. `zcl_foo`
+    #[serde(rename = "@matches")]
+    pub matches: Option<String>,
+
+    /// The source code of the line the reference occurs in.
+    #[serde(rename = "usageReferences:content")]
+    pub content: String,
+
+    /// Description of the snippet, e.g. the name of the method it occurs in.
+    #[serde(rename = "usageReferences:description")]
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::abapsource::Position;
+
+    #[test]
+    fn serialize_usage_reference_request_without_scope() {
+        let request = UsageReferenceRequest::new(&[], &[]);
+        assert_eq!(
+            request.into_xml_root().unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <usageReferences:usageReferenceRequest xmlns:usageReferences=\"http://www.sap.com/adt/ris/usageReferences\">\
+                <usageReferences:affectedObjects />\
+            </usageReferences:usageReferenceRequest>"
+        );
+    }
+
+    #[test]
+    fn serialize_usage_reference_request_with_scope() {
+        let types = vec![Cow::Borrowed("CLAS/OC"), Cow::Borrowed("PROG/P")];
+        let packages = vec![Cow::Borrowed("$TMP")];
+
+        let request = UsageReferenceRequest::new(&types, &packages);
+        assert_eq!(
+            serde_xml_rs::to_string(&request).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <usageReferences:usageReferenceRequest>\
+                <usageReferences:scope>\
+                    <usageReferences:objectTypes>\
+                        <usageReferences:type name=\"CLAS/OC\" isSelected=\"true\" />\
+                        <usageReferences:type name=\"PROG/P\" isSelected=\"true\" />\
+                    </usageReferences:objectTypes>\
+                    <usageReferences:packages>\
+                        <usageReferences:package name=\"$TMP\" />\
+                    </usageReferences:packages>\
+                </usageReferences:scope>\
+                <usageReferences:affectedObjects />\
+            </usageReferences:usageReferenceRequest>"
+        );
+    }
+
+    #[test]
+    fn serialize_usage_snippet_request() {
+        let objects = vec![SnippetObject::new(
+            "ABAPFullName;ZCL_FOO;",
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+        )];
+
+        let request = UsageSnippetRequest::new(&objects);
+        assert_eq!(
+            serde_xml_rs::to_string(&request).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <usageReferences:usageSnippetRequest>\
+                <usageReferences:objectIdentifiers>\
+                    <usageReferences:objectIdentifier optional=\"false\">ABAPFullName;ZCL_FOO;</usageReferences:objectIdentifier>\
+                </usageReferences:objectIdentifiers>\
+                <usageReferences:affectedObjects>\
+                    <usageReferences:affectedObject uri=\"/sap/bc/adt/programs/programs/zwegwerf1/source/main\" />\
+                </usageReferences:affectedObjects>\
+            </usageReferences:usageSnippetRequest>"
+        );
+    }
+
+    #[test]
+    fn deserialize_usage_reference_result() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <usageReferences:usageReferenceResult xmlns:usageReferences="http://www.sap.com/adt/ris/usageReferences" numberOfResults="1" resultDescription="Where-used list for ZCL_FOO" referencedObjectIdentifier="ABAPFullName;ZCL_FOO;">
+                <usageReferences:referencedObjects>
+                    <usageReferences:referencedObject uri="/sap/bc/adt/packages/%24tmp" isResult="false" canHaveChildren="true">
+                        <usageReferences:adtObject adtcore:name="$TMP" adtcore:type="DEVC/K" adtcore:description="Local Objects" xmlns:adtcore="http://www.sap.com/adt/core"/>
+                    </usageReferences:referencedObject>
+                    <usageReferences:referencedObject uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main" parentUri="/sap/bc/adt/packages/%24tmp" isResult="true" canHaveChildren="false" usageInformation="gradeDirect,includeProductive" objectIdentifier="ABAPFullName;ZWEGWERF1;">
+                        <usageReferences:adtObject adtcore:name="ZWEGWERF1" adtcore:type="PROG/P" adtcore:description="test" adtcore:responsible="DEVELOPER" xmlns:adtcore="http://www.sap.com/adt/core">
+                            <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                        </usageReferences:adtObject>
+                    </usageReferences:referencedObject>
+                </usageReferences:referencedObjects>
+            </usageReferences:usageReferenceResult>"#;
+
+        let result: UsageReferenceResult = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.number_of_results, 1);
+        assert_eq!(result.roots().count(), 1);
+        assert_eq!(result.children("/sap/bc/adt/packages/%24tmp").count(), 1);
+
+        let referencing = result.results().next().unwrap();
+        assert_eq!(referencing.object.name, "ZWEGWERF1");
+        assert_eq!(
+            referencing.object.package.as_ref().map(|p| p.name.as_str()),
+            Some("$TMP")
+        );
+    }
+
+    #[test]
+    fn deserialize_usage_snippet_result() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <usageReferences:usageSnippetResult xmlns:usageReferences="http://www.sap.com/adt/ris/usageReferences">
+                <usageReferences:codeSnippetObjects>
+                    <usageReferences:codeSnippetObject>
+                        <usageReferences:objectIdentifier>ABAPFullName;ZWEGWERF1;</usageReferences:objectIdentifier>
+                        <usageReferences:codeSnippets>
+                            <usageReferences:codeSnippet uri="/sap/bc/adt/programs/programs/zwegwerf1/source/main#start=12,10;end=12,17" matches="zcl_foo">
+                                <usageReferences:content>DATA(lo_foo) = NEW zcl_foo( ).</usageReferences:content>
+                                <usageReferences:description>ZWEGWERF1</usageReferences:description>
+                            </usageReferences:codeSnippet>
+                        </usageReferences:codeSnippets>
+                    </usageReferences:codeSnippetObject>
+                </usageReferences:codeSnippetObjects>
+            </usageReferences:usageSnippetResult>"#;
+
+        let result: UsageSnippetResult = serde_xml_rs::from_str(plain).unwrap();
+        let object = &result.snippet_objects.objects[0];
+        let snippet = &object.snippets.snippets[0];
+        assert_eq!(object.object_identifier, "ABAPFullName;ZWEGWERF1;");
+        assert_eq!(
+            snippet.location.uri,
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main"
+        );
+        assert_eq!(snippet.location.start, Position::new(12, 10));
+        assert_eq!(snippet.location.end, Some(Position::new(12, 17)));
+    }
+}
@@ -54,11 +54,11 @@ where
 }
 
 #[derive(Debug)]
-pub struct Success<T: DeserializeOwned>(http::Response<T>);
+pub struct Success<T: DeserializeResponse>(http::Response<T>);
 
 impl<T> Deref for Success<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeResponse,
 {
     type Target = http::Response<T>;
     fn deref(&self) -> &Self::Target {
@@ -68,17 +68,18 @@ where
 
 impl<T> TryFrom<http::Response<String>> for Success<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeResponse,
 {
     type Error = ResponseError;
 
     fn try_from(value: http::Response<String>) -> Result<Self, Self::Error> {
         match value.status() {
-            StatusCode::OK => {
+            // Creation of objects is acknowledged with `201 Created` and the new object.
+            StatusCode::OK | StatusCode::CREATED => {
                 let (res, body) = value.into_parts();
                 Ok(Self(http::Response::from_parts(
                     res,
-                    serde_xml_rs::from_str(&body)?,
+                    T::deserialize_response(body)?,
                 )))
             }
             _ => Err(ResponseError::BadStatusCode(value)),
@@ -0,0 +1,80 @@
+/// A fake system to unit test the helpers that chain several operations.
+use std::{
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use http::{Method, Response, request::Builder as RequestBuilder};
+use url::Url;
+
+use crate::{
+    Client, ClientBuilder, ConnectionParameters, HttpConnectionBuilder, RequestDispatch,
+    auth::Credentials, error::DispatchError,
+};
+
+/// Answers every request with the same body, or refuses the connection if there is none,
+/// and records the method and path of what was requested.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct FakeSystem {
+    body: Option<String>,
+    requests: Arc<Mutex<Vec<(Method, String)>>>,
+}
+
+impl FakeSystem {
+    /// A system that cannot be reached, for logic that must not need the server.
+    pub(crate) fn offline() -> Self {
+        Self::default()
+    }
+
+    /// A system that answers every request with status 200 and the given body.
+    pub(crate) fn responding(body: &str) -> Self {
+        Self {
+            body: Some(body.to_owned()),
+            ..Self::default()
+        }
+    }
+
+    /// A client that dispatches its requests to this system.
+    pub(crate) fn client(&self) -> Client<Self> {
+        let params = HttpConnectionBuilder::default()
+            .hostname(Url::from_str("http://localhost:50000").unwrap())
+            .client("001")
+            .language("en")
+            .build()
+            .unwrap();
+        ClientBuilder::default()
+            .connection_params(ConnectionParameters::Http(params))
+            .credentials(Credentials::new("DEVELOPER", "secret"))
+            .dispatcher(self.clone())
+            .build()
+            .unwrap()
+    }
+
+    /// Whether a request with the given method to a path ending with `path` was made.
+    pub(crate) fn requested(&self, method: Method, path: &str) -> bool {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(m, p)| *m == method && p.ends_with(path))
+    }
+}
+
+#[async_trait]
+impl RequestDispatch for FakeSystem {
+    async fn dispatch_request(
+        &self,
+        request: RequestBuilder,
+        _body: String,
+    ) -> Result<Response<String>, DispatchError> {
+        self.requests.lock().unwrap().push((
+            request.method_ref().unwrap().clone(),
+            request.uri_ref().unwrap().path().to_owned(),
+        ));
+        match &self.body {
+            Some(body) => Ok(Response::builder().status(200).body(body.clone())?),
+            None => Err(DispatchError::ConnectionRefused),
+        }
+    }
+}
@@ -0,0 +1,567 @@
+/// URI Templates as specified in [RFC 6570][rfc], supporting all four levels.
+///
+/// ADT advertises many of its resources as templates rather than plain URIs, for example
+/// as part of the discovery or through [`crate::models::adtcomp::TemplateLink`]s:
+/// ```text
+/// /sap/bc/adt/repository/informationsystem/properties/values?data=owner{&name}
+/// ```
+///
+/// [rfc]: https://datatracker.ietf.org/doc/html/rfc6570
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use crate::error::TemplateError;
+
+/// The value of a variable in a template expansion, see [RFC 6570 Section 2.3][rfc]
+///
+/// Empty lists and associative arrays are considered undefined and are not expanded.
+///
+/// [rfc]: https://datatracker.ietf.org/doc/html/rfc6570#section-2.3
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateValue<'a> {
+    String(Cow<'a, str>),
+    List(Vec<Cow<'a, str>>),
+    Map(Vec<(Cow<'a, str>, Cow<'a, str>)>),
+}
+
+impl<'a> From<&'a str> for TemplateValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl From<String> for TemplateValue<'_> {
+    fn from(value: String) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for TemplateValue<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<'a, T> From<Vec<T>> for TemplateValue<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(value: Vec<T>) -> Self {
+        Self::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+/// The variables that a [`UriTemplate`] is expanded with.
+///
+/// Variables that are part of the template but not set are considered undefined and
+/// are skipped during the expansion, as the specification requires.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateVariables<'a> {
+    values: HashMap<Cow<'a, str>, TemplateValue<'a>>,
+}
+
+impl<'a> TemplateVariables<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of a variable, replacing any previous value.
+    pub fn set<K, V>(&mut self, name: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<TemplateValue<'a>>,
+    {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the value of a variable only if one is provided.
+    pub fn set_opt<K, V>(&mut self, name: K, value: Option<V>) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<TemplateValue<'a>>,
+    {
+        if let Some(value) = value {
+            self.set(name, value);
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TemplateValue<'a>> {
+        self.values.get(name)
+    }
+}
+
+/// The operator of an expression, determines the prefix, seperator and encoding.
+///
+/// See the appendix of [RFC 6570 Section A][rfc] for the behavior of each operator.
+///
+/// [rfc]: https://datatracker.ietf.org/doc/html/rfc6570#appendix-A
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    Simple,
+    Reserved,
+    Fragment,
+    Label,
+    Path,
+    PathParameter,
+    Query,
+    QueryContinuation,
+}
+
+impl Operator {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Self::Reserved),
+            '#' => Some(Self::Fragment),
+            '.' => Some(Self::Label),
+            '/' => Some(Self::Path),
+            ';' => Some(Self::PathParameter),
+            '?' => Some(Self::Query),
+            '&' => Some(Self::QueryContinuation),
+            _ => None,
+        }
+    }
+
+    fn first(&self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved => "",
+            Self::Fragment => "#",
+            Self::Label => ".",
+            Self::Path => "/",
+            Self::PathParameter => ";",
+            Self::Query => "?",
+            Self::QueryContinuation => "&",
+        }
+    }
+
+    fn separator(&self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved | Self::Fragment => ",",
+            Self::Label => ".",
+            Self::Path => "/",
+            Self::PathParameter => ";",
+            Self::Query | Self::QueryContinuation => "&",
+        }
+    }
+
+    fn named(&self) -> bool {
+        matches!(
+            self,
+            Self::PathParameter | Self::Query | Self::QueryContinuation
+        )
+    }
+
+    fn if_empty(&self) -> &'static str {
+        match self {
+            Self::Query | Self::QueryContinuation => "=",
+            _ => "",
+        }
+    }
+
+    fn allow_reserved(&self) -> bool {
+        matches!(self, Self::Reserved | Self::Fragment)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Modifier {
+    None,
+    Prefix(usize),
+    Explode,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct VarSpec {
+    name: String,
+    modifier: Modifier,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Literal(String),
+    Expression(Operator, Vec<VarSpec>),
+}
+
+/// A parsed URI Template that can be expanded with a set of [`TemplateVariables`].
+///
+/// ## Example:
+/// ```
+/// use adt_query::uritemplate::{TemplateVariables, UriTemplate};
+///
+/// let template: UriTemplate = "/sap/bc/adt/repository/informationsystem/properties/values?data=owner{&name}"
+///     .parse()
+///     .unwrap();
+///
+/// let mut variables = TemplateVariables::new();
+/// variables.set("name", "DEV*");
+///
+/// assert_eq!(
+///     template.expand(&variables),
+///     "/sap/bc/adt/repository/informationsystem/properties/values?data=owner&name=DEV%2A"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UriTemplate {
+    parts: Vec<Part>,
+}
+
+impl UriTemplate {
+    /// The names of all variables referenced in the template, in order of appearance.
+    pub fn variables(&self) -> Vec<&str> {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                Part::Expression(_, specs) => Some(specs.iter().map(|s| s.name.as_str())),
+                Part::Literal(_) => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Expands the template, undefined variables are omitted from the result.
+    pub fn expand(&self, variables: &TemplateVariables) -> String {
+        let mut result = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => encode_into(&mut result, literal, true),
+                Part::Expression(op, specs) => {
+                    expand_expression(&mut result, *op, specs, variables)
+                }
+            }
+        }
+        result
+    }
+}
+
+impl FromStr for UriTemplate {
+    type Err = TemplateError;
+
+    fn from_str(template: &str) -> Result<Self, Self::Err> {
+        let mut parts = Vec::new();
+        let mut rest = template;
+
+        while !rest.is_empty() {
+            let offset = template.len() - rest.len();
+            match rest.find(['{', '}']) {
+                None => {
+                    parts.push(Part::Literal(rest.to_owned()));
+                    break;
+                }
+                Some(pos) if rest[pos..].starts_with('}') => {
+                    return Err(TemplateError::UnexpectedClose(offset + pos));
+                }
+                Some(pos) => {
+                    if pos > 0 {
+                        parts.push(Part::Literal(rest[..pos].to_owned()));
+                    }
+                    let end = rest[pos..]
+                        .find('}')
+                        .ok_or(TemplateError::UnclosedExpression(offset + pos))?;
+                    parts.push(parse_expression(&rest[pos + 1..pos + end])?);
+                    rest = &rest[pos + end + 1..];
+                }
+            }
+        }
+        Ok(Self { parts })
+    }
+}
+
+fn parse_expression(expression: &str) -> Result<Part, TemplateError> {
+    let invalid = || TemplateError::InvalidExpression(expression.to_owned());
+
+    let mut chars = expression.chars();
+    let (op, varlist) = match chars.next().and_then(Operator::from_char) {
+        Some(op) => (op, chars.as_str()),
+        None => (Operator::Simple, expression),
+    };
+
+    let specs = varlist
+        .split(',')
+        .map(|spec| {
+            let (name, modifier) = if let Some(name) = spec.strip_suffix('*') {
+                (name, Modifier::Explode)
+            } else if let Some((name, length)) = spec.split_once(':') {
+                match length.parse::<usize>() {
+                    Ok(n @ 1..10000) => (name, Modifier::Prefix(n)),
+                    _ => return Err(invalid()),
+                }
+            } else {
+                (spec, Modifier::None)
+            };
+
+            let valid_name = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '%'));
+            if !valid_name {
+                return Err(invalid());
+            }
+            Ok(VarSpec {
+                name: name.to_owned(),
+                modifier,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Part::Expression(op, specs))
+}
+
+fn expand_expression(
+    result: &mut String,
+    op: Operator,
+    specs: &[VarSpec],
+    variables: &TemplateVariables,
+) {
+    let mut first = true;
+    let reserved = op.allow_reserved();
+
+    for spec in specs {
+        let value = match variables.get(&spec.name) {
+            Some(TemplateValue::List(l)) if l.is_empty() => continue,
+            Some(TemplateValue::Map(m)) if m.is_empty() => continue,
+            Some(value) => value,
+            None => continue,
+        };
+        result.push_str(if first { op.first() } else { op.separator() });
+        first = false;
+
+        match value {
+            TemplateValue::String(s) => {
+                if op.named() {
+                    result.push_str(&spec.name);
+                    if s.is_empty() {
+                        result.push_str(op.if_empty());
+                        continue;
+                    }
+                    result.push('=');
+                }
+                let s = match spec.modifier {
+                    Modifier::Prefix(n) => s.char_indices().nth(n).map_or(&s[..], |(i, _)| &s[..i]),
+                    _ => s,
+                };
+                encode_into(result, s, reserved);
+            }
+            TemplateValue::List(items) if spec.modifier == Modifier::Explode => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        result.push_str(op.separator());
+                    }
+                    if op.named() {
+                        result.push_str(&spec.name);
+                        if item.is_empty() {
+                            result.push_str(op.if_empty());
+                            continue;
+                        }
+                        result.push('=');
+                    }
+                    encode_into(result, item, reserved);
+                }
+            }
+            TemplateValue::List(items) => {
+                if op.named() {
+                    result.push_str(&spec.name);
+                    result.push('=');
+                }
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        result.push(',');
+                    }
+                    encode_into(result, item, reserved);
+                }
+            }
+            TemplateValue::Map(pairs) if spec.modifier == Modifier::Explode => {
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        result.push_str(op.separator());
+                    }
+                    encode_into(result, key, reserved);
+                    if op.named() && value.is_empty() {
+                        result.push_str(op.if_empty());
+                        continue;
+                    }
+                    result.push('=');
+                    encode_into(result, value, reserved);
+                }
+            }
+            TemplateValue::Map(pairs) => {
+                if op.named() {
+                    result.push_str(&spec.name);
+                    result.push('=');
+                }
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        result.push(',');
+                    }
+                    encode_into(result, key, reserved);
+                    result.push(',');
+                    encode_into(result, value, reserved);
+                }
+            }
+        }
+    }
+}
+
+/// Percent-encodes a single path segment, e.g. `/ABC/PKG` into `%2FABC%2FPKG`
+pub(crate) fn encode_segment(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    encode_into(&mut result, value, false);
+    result
+}
+
+/// Percent-encodes the value, only unreserved characters are kept unless `reserved` is
+/// set, in which case reserved characters and existing pct-encoded triplets are kept too.
+fn encode_into(result: &mut String, value: &str, reserved: bool) {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let is_triplet = b == b'%'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+            && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit);
+
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            result.push(b as char);
+        } else if reserved && is_triplet {
+            result.push_str(&value[i..i + 3]);
+            i += 3;
+            continue;
+        } else if reserved && b":/?#[]@!$&'()*+,;=".contains(&b) {
+            result.push(b as char);
+        } else {
+            result.push_str(&format!("%{b:02X}"));
+        }
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Variables used throughout the examples of RFC 6570 Section 3.2
+    fn rfc_variables() -> TemplateVariables<'static> {
+        let mut vars = TemplateVariables::new();
+        vars.set("count", vec!["one", "two", "three"])
+            .set("dom", vec!["example", "com"])
+            .set("dub", "me/too")
+            .set("hello", "Hello World!")
+            .set("half", "50%")
+            .set("var", "value")
+            .set("who", "fred")
+            .set("base", "http://example.com/home/")
+            .set("path", "/foo/bar")
+            .set("list", vec!["red", "green", "blue"])
+            .set(
+                "keys",
+                TemplateValue::Map(vec![
+                    ("semi".into(), ";".into()),
+                    ("dot".into(), ".".into()),
+                    ("comma".into(), ",".into()),
+                ]),
+            )
+            .set("v", "6")
+            .set("x", "1024")
+            .set("y", "768")
+            .set("empty", "")
+            .set("empty_keys", TemplateValue::Map(vec![]));
+        vars
+    }
+
+    fn expand(template: &str) -> String {
+        template
+            .parse::<UriTemplate>()
+            .unwrap()
+            .expand(&rfc_variables())
+    }
+
+    #[test]
+    fn simple_string_expansion() {
+        assert_eq!(expand("{var}"), "value");
+        assert_eq!(expand("{hello}"), "Hello%20World%21");
+        assert_eq!(expand("{half}"), "50%25");
+        assert_eq!(expand("O{empty}X"), "OX");
+        assert_eq!(expand("O{undef}X"), "OX");
+        assert_eq!(expand("{x,y}"), "1024,768");
+        assert_eq!(expand("{var:3}"), "val");
+        assert_eq!(expand("{list}"), "red,green,blue");
+        assert_eq!(expand("{keys}"), "semi,%3B,dot,.,comma,%2C");
+        assert_eq!(expand("{keys*}"), "semi=%3B,dot=.,comma=%2C");
+    }
+
+    #[test]
+    fn reserved_and_fragment_expansion() {
+        assert_eq!(expand("{+path}/here"), "/foo/bar/here");
+        assert_eq!(expand("{+base}index"), "http://example.com/home/index");
+        assert_eq!(expand("{+half}"), "50%25");
+        assert_eq!(expand("{#var}"), "#value");
+        assert_eq!(expand("{#hello}"), "#Hello%20World!");
+        assert_eq!(expand("X{#list*}"), "X#red,green,blue");
+    }
+
+    #[test]
+    fn label_and_path_expansion() {
+        assert_eq!(expand("www{.dom*}"), "www.example.com");
+        assert_eq!(expand("X{.empty_keys}"), "X");
+        assert_eq!(expand("{/who,dub}"), "/fred/me%2Ftoo");
+        assert_eq!(expand("{/list*,path:4}"), "/red/green/blue/%2Ffoo");
+        assert_eq!(expand("{/count*}"), "/one/two/three");
+    }
+
+    #[test]
+    fn path_parameter_expansion() {
+        assert_eq!(expand("{;x,y,empty}"), ";x=1024;y=768;empty");
+        assert_eq!(expand("{;list*}"), ";list=red;list=green;list=blue");
+        assert_eq!(expand("{;keys*}"), ";semi=%3B;dot=.;comma=%2C");
+    }
+
+    #[test]
+    fn query_expansion() {
+        assert_eq!(expand("{?x,y,empty}"), "?x=1024&y=768&empty=");
+        assert_eq!(expand("{?x,undef}"), "?x=1024");
+        assert_eq!(expand("{?list}"), "?list=red,green,blue");
+        assert_eq!(expand("{?list*}"), "?list=red&list=green&list=blue");
+        assert_eq!(expand("?fixed=yes{&x}"), "?fixed=yes&x=1024");
+        assert_eq!(expand("{&keys*}"), "&semi=%3B&dot=.&comma=%2C");
+    }
+
+    #[test]
+    fn adt_template_link_is_expanded() {
+        let template: UriTemplate = "/sap/bc/adt/repository/informationsystem/search{?operation,query,maxResults}{&objectType*}"
+            .parse()
+            .unwrap();
+
+        let mut vars = TemplateVariables::new();
+        vars.set("operation", "quickSearch")
+            .set("query", "ZWEG*")
+            .set("objectType", vec!["PROG/P", "CLAS/OC"]);
+
+        assert_eq!(
+            template.variables(),
+            vec!["operation", "query", "maxResults", "objectType"]
+        );
+        assert_eq!(
+            template.expand(&vars),
+            "/sap/bc/adt/repository/informationsystem/search?operation=quickSearch&query=ZWEG%2A&objectType=PROG%2FP&objectType=CLAS%2FOC"
+        );
+    }
+
+    #[test]
+    fn malformed_templates_are_rejected() {
+        assert!(matches!(
+            "/foo{?bar".parse::<UriTemplate>(),
+            Err(TemplateError::UnclosedExpression(4))
+        ));
+        assert!(matches!(
+            "/foo}".parse::<UriTemplate>(),
+            Err(TemplateError::UnexpectedClose(4))
+        ));
+        assert!(matches!(
+            "{?}".parse::<UriTemplate>(),
+            Err(TemplateError::InvalidExpression(_))
+        ));
+        assert!(matches!(
+            "{var:0}".parse::<UriTemplate>(),
+            Err(TemplateError::InvalidExpression(_))
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+use adt_query::{
+    api::{
+        abapsource,
+        object::{self, SourceCodeObject},
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::abapsource::{Position, SourceLocation},
+};
+
+mod common;
+
+#[tokio::test]
+async fn source_is_pretty_printed() {
+    let client = common::setup_test_system_client();
+
+    let op = abapsource::PrettyPrintBuilder::default()
+        .source(
+            "report zwegwerf1.\ndata lv_test type i.\nif lv_test = 1.\nwrite lv_test.\nendif.\n",
+        )
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(result.body().contains("ENDIF"));
+}
+
+#[tokio::test]
+async fn settings_are_written_back() {
+    let client = common::setup_test_system_client();
+
+    let settings = abapsource::PrettyPrinterSettingsRead::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+
+    let op = abapsource::PrettyPrinterSettingsUpdateBuilder::default()
+        .settings(settings.body())
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn locked_program_is_pretty_printed() {
+    let client = common::setup_test_system_client();
+    let ctx = client.create_user_session();
+
+    let op = object::LockBuilder::default()
+        .object_uri("programs/programs/zwegwerf1")
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+    let lock = op.dispatch(&client, ctx).await.unwrap();
+
+    let object = SourceCodeObject::Program("zwegwerf1".into());
+    abapsource::pretty_print_object(&client, ctx, &object, &lock.body().lock_handle, None)
+        .await
+        .unwrap();
+
+    // Formatting twice does not change anything
+    let changed =
+        abapsource::pretty_print_object(&client, ctx, &object, &lock.body().lock_handle, None)
+            .await
+            .unwrap();
+    assert!(!changed);
+
+    let op = object::UnlockBuilder::default()
+        .object_uri("programs/programs/zwegwerf1")
+        .lock_handle(lock.body().lock_handle.as_str())
+        .build()
+        .unwrap();
+    op.dispatch(&client, ctx).await.unwrap();
+}
+
+const COMPLETION_SOURCE: &str = "REPORT zwegwerf1.\nDATA lv_counter TYPE i.\nlv_c\n";
+
+fn completion_location(column: u32) -> SourceLocation {
+    SourceLocation::new(
+        "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+        Position::new(3, column),
+        None,
+    )
+}
+
+#[tokio::test]
+async fn variable_is_proposed() {
+    let client = common::setup_test_system_client();
+
+    let op = abapsource::CompletionProposalsBuilder::default()
+        .location(completion_location(4))
+        .source(COMPLETION_SOURCE)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(
+        result
+            .body()
+            .proposals()
+            .any(|proposal| proposal.identifier.eq_ignore_ascii_case("lv_counter"))
+    );
+}
+
+#[tokio::test]
+async fn element_info_of_variable_is_provided() {
+    let client = common::setup_test_system_client();
+
+    let op = abapsource::CompletionElementInfoBuilder::default()
+        .location(completion_location(2))
+        .source(COMPLETION_SOURCE.replace("lv_c\n", "lv_counter = 1.\n"))
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(result.body().name.eq_ignore_ascii_case("lv_counter"));
+}
+
+#[tokio::test]
+async fn occurrences_of_variable_are_marked() {
+    let client = common::setup_test_system_client();
+
+    let op = abapsource::OccurrenceMarkersBuilder::default()
+        .location(completion_location(2))
+        .source(COMPLETION_SOURCE.replace("lv_c\n", "lv_counter = lv_counter + 1.\n"))
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert_eq!(result.body().occurrences.len(), 3);
+    assert!(result.body().definition().is_some());
+}
+
+#[tokio::test]
+async fn sub_types_of_class_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = abapsource::TypeHierarchyBuilder::default()
+        .location(SourceLocation::new(
+            "/sap/bc/adt/oo/classes/zcl_demo1/source/main",
+            Position::new(1, 6),
+            None,
+        ))
+        .direction(abapsource::HierarchyDirection::SubTypes)
+        .source("CLASS zcl_demo1 DEFINITION PUBLIC CREATE PUBLIC.\nENDCLASS.\nCLASS zcl_demo1 IMPLEMENTATION.\nENDCLASS.\n")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(result.body().classes().all(|entry| !entry.is_interface()));
+}
@@ -0,0 +1,66 @@
+use adt_query::{
+    api::abapunit,
+    dispatch::StatelessDispatch,
+    models::abapunit::{RiskLevel, RunConfigurationBuilder, TestDuration},
+};
+
+mod common;
+
+#[tokio::test]
+async fn metadata_is_retrieved() {
+    let client = common::setup_test_system_client();
+
+    let result = abapunit::Metadata::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    assert!(result.body().coverage.is_some());
+}
+
+#[tokio::test]
+async fn class_tests_are_run_with_coverage() {
+    let client = common::setup_test_system_client();
+
+    let config = RunConfigurationBuilder::default()
+        .object("/sap/bc/adt/oo/classes/zcl_demo1")
+        .max_risk_level(RiskLevel::Harmless)
+        .max_duration(TestDuration::Short)
+        .coverage(true)
+        .build()
+        .unwrap();
+
+    let op = abapunit::RunTestsBuilder::default()
+        .config(&config)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().test_methods().count() > 0);
+
+    let measurement = result.body().coverage().unwrap();
+    let op = abapunit::CoverageMeasurementBuilder::default()
+        .measurement_uri(&measurement.uri)
+        .object("/sap/bc/adt/oo/classes/zcl_demo1")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().nodes.nodes.is_empty());
+}
+
+#[tokio::test]
+async fn package_tests_are_run() {
+    let client = common::setup_test_system_client();
+
+    let config = RunConfigurationBuilder::default()
+        .package("$TMP")
+        .build()
+        .unwrap();
+
+    let op = abapunit::RunTestsBuilder::default()
+        .config(&config)
+        .build()
+        .unwrap();
+
+    op.dispatch(&client).await.unwrap();
+}
@@ -0,0 +1,95 @@
+use adt_query::{
+    api::activation,
+    dispatch::StatelessDispatch,
+    models::activation::{ActivationObjectBuilder, ActivationObjectsBuilder},
+};
+
+mod common;
+
+#[tokio::test]
+async fn program_is_activated() {
+    let client = common::setup_test_system_client();
+
+    let objects = ActivationObjectsBuilder::default()
+        .object(
+            ActivationObjectBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                .name("ZWEGWERF1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let op = activation::ActivateBuilder::default()
+        .objects(&objects)
+        .preaudit(true)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().is_activated());
+}
+
+#[tokio::test]
+async fn inactive_objects_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = activation::InactiveObjects::default();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(
+        result
+            .body()
+            .objects()
+            .all(|object| !object.reference.uri.is_empty())
+    );
+}
+
+#[tokio::test]
+async fn activation_run_is_polled_until_finished() {
+    let client = common::setup_test_system_client();
+
+    let objects = ActivationObjectsBuilder::default()
+        .object(
+            ActivationObjectBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                .name("ZWEGWERF1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let op = activation::StartActivationRunBuilder::default()
+        .objects(&objects)
+        .build()
+        .unwrap();
+    let started = op.dispatch(&client).await.unwrap();
+    let id = activation::run_id(&started).unwrap().to_string();
+
+    let op = activation::ActivationRunStatusBuilder::default()
+        .id(&id)
+        .long_polling(true)
+        .build()
+        .unwrap();
+    while !op.dispatch(&client).await.unwrap().body().is_finished() {}
+
+    let op = activation::ActivationRunResultBuilder::default()
+        .id(&id)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.body().errors().count(), 0);
+}
+
+#[tokio::test]
+async fn inactive_objects_of_user_are_activated() {
+    let client = common::setup_test_system_client();
+
+    if let Some(result) = activation::activate_inactive(&client, "DEVELOPER")
+        .await
+        .unwrap()
+    {
+        assert!(result.body().is_activated());
+    }
+}
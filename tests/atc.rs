@@ -0,0 +1,85 @@
+use adt_query::{
+    api::atc,
+    dispatch::StatelessDispatch,
+    models::atc::{ExemptionRequestBuilder, RunRequestBuilder},
+};
+
+mod common;
+
+#[tokio::test]
+async fn customizing_and_check_variants_are_retrieved() {
+    let client = common::setup_test_system_client();
+
+    let result = atc::AtcCustomizing::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    assert!(result.body().system_check_variant().is_some());
+
+    let op = atc::CheckVariantsBuilder::default()
+        .name("*")
+        .max_items(10u64)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().items.is_empty());
+}
+
+#[tokio::test]
+async fn findings_of_run_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let customizing = atc::AtcCustomizing::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    let variant = customizing.body().system_check_variant().unwrap();
+
+    let op = atc::CreateWorklistBuilder::default()
+        .check_variant(variant)
+        .build()
+        .unwrap();
+    let worklist = op.dispatch(&client).await.unwrap();
+    let worklist_id = worklist.body().to_string();
+
+    let request = RunRequestBuilder::default()
+        .object("/sap/bc/adt/oo/classes/zcl_demo1")
+        .build()
+        .unwrap();
+    let op = atc::RunChecksBuilder::default()
+        .worklist_id(&worklist_id)
+        .request(&request)
+        .build()
+        .unwrap();
+    let run = op.dispatch(&client).await.unwrap();
+
+    let op = atc::WorklistBuilder::default()
+        .id(&worklist_id)
+        .timestamp(run.body().timestamp)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    for finding in result.body().findings() {
+        assert!(!finding.check_title.is_empty());
+        assert!((1..=4).contains(&finding.priority));
+    }
+}
+
+#[tokio::test]
+async fn exemption_for_unknown_finding_is_rejected() {
+    let client = common::setup_test_system_client();
+
+    let request = ExemptionRequestBuilder::default()
+        .finding_uri("/sap/bc/adt/atc/items/itemid/0000/index/0")
+        .reason("FPOS")
+        .justification("Does not exist.")
+        .approver("DEVELOPER")
+        .build()
+        .unwrap();
+
+    let op = atc::RequestExemptionBuilder::default()
+        .request(&request)
+        .build()
+        .unwrap();
+    assert!(op.dispatch(&client).await.is_err());
+}
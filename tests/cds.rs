@@ -0,0 +1,107 @@
+use adt_query::{
+    api::{
+        activation,
+        cds::{self, CdsSourceKind},
+        object,
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::activation::ActivationObjectsBuilder,
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn data_definition_source_is_not_refetched_with_etag() {
+    let client = common::setup_test_system_client();
+
+    let op = cds::CdsSourceBuilder::default()
+        .name("zi_demo1")
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(source) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the data definition to be fetched without cache.");
+    };
+    assert_eq!(source.body().object_type, "DDLS/DF");
+
+    let op = cds::CdsSourceCodeBuilder::default()
+        .name("zi_demo1")
+        .etag(source.body().source_etag().unwrap())
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(matches!(result, CacheControlled::NotModified(_)))
+}
+
+#[tokio::test]
+async fn dependencies_of_data_definition_are_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = cds::DependencyGraphBuilder::default()
+        .name("zi_demo1")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().nodes.is_empty());
+
+    let op = cds::RelatedObjectsBuilder::default()
+        .name("zi_demo1")
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn data_definition_is_updated_and_activated() {
+    let client = common::setup_test_system_client();
+    let kind = CdsSourceKind::DataDefinition;
+
+    let op = cds::CdsSourceCodeBuilder::default()
+        .name("zi_demo1")
+        .build()
+        .unwrap();
+    let CacheControlled::Modified(source) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the source to be fetched without cache.");
+    };
+    let source = source.body().to_string();
+
+    let object_uri = kind.source_object("zi_demo1").object_uri();
+    let op = object::LockBuilder::default()
+        .object_uri(object_uri.as_str())
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+
+    let ctx = client.create_user_session();
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    let handle = &result.body().lock_handle;
+
+    let op = object::UpdateSourceCodeBuilder::default()
+        .object(kind.source_object("zi_demo1"))
+        .content(source)
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+    op.dispatch(&client, ctx).await.unwrap();
+
+    let op = object::UnlockBuilder::default()
+        .object_uri(object_uri.as_str())
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+    op.dispatch(&client, ctx).await.unwrap();
+
+    let objects = ActivationObjectsBuilder::default()
+        .object(kind.activation_object("zi_demo1"))
+        .build()
+        .unwrap();
+    let op = activation::ActivateBuilder::default()
+        .objects(&objects)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().is_activated());
+}
@@ -0,0 +1,89 @@
+use adt_query::{
+    api::checkruns,
+    dispatch::StatelessDispatch,
+    models::{
+        abapsource::Severity,
+        checkrun::{Artifact, ObjectBuilder, ObjectListBuilder},
+    },
+};
+
+mod common;
+
+#[tokio::test]
+async fn reporters_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let result = checkruns::Reporters::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    assert!(
+        result
+            .body()
+            .reporters
+            .iter()
+            .any(|reporter| reporter.name == "abapCheckRun")
+    );
+}
+
+#[tokio::test]
+async fn saved_program_is_checked() {
+    let client = common::setup_test_system_client();
+
+    let op = checkruns::RunCheckBuilder::default()
+        .objects(
+            ObjectListBuilder::default()
+                .object(
+                    ObjectBuilder::default()
+                        .object_uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                        .version("active")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .reporter("abapCheckRun")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.body().reports.len(), 1);
+}
+
+#[tokio::test]
+async fn unsaved_content_is_checked() {
+    let client = common::setup_test_system_client();
+
+    let op = checkruns::RunCheckBuilder::default()
+        .objects(
+            ObjectListBuilder::default()
+                .object(
+                    ObjectBuilder::default()
+                        .object_uri("/sap/bc/adt/programs/programs/zwegwerf1")
+                        .version("inactive")
+                        .artifact(Artifact::source(
+                            "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+                            "REPORT zwegwerf1.\nWRTE 'Hello'.",
+                        ))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .reporter("abapCheckRun")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    let messages = &result.body().reports[0].messages.as_ref().unwrap().messages;
+    let error = messages
+        .iter()
+        .find(|message| message.severity == Severity::Error)
+        .unwrap();
+    assert_eq!(
+        error.location().map(|location| location.start.line),
+        Some(2)
+    );
+}
@@ -0,0 +1,102 @@
+use adt_query::{
+    api::{
+        classes,
+        object::{self, SourceCodeObject},
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::{adtcore, class::ClassInclude},
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn class_data_is_fetched_without_cache() {
+    let client = common::setup_test_system_client();
+
+    let op = classes::ClassBuilder::default()
+        .name("ZCL_DEMO1")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    let CacheControlled::Modified(class) = result else {
+        panic!("Expected the class to be fetched without cache.");
+    };
+    assert!(class.body().include(ClassInclude::Main).is_some());
+}
+
+#[tokio::test]
+async fn class_include_source_is_not_refetched_with_etag() {
+    let client = common::setup_test_system_client();
+
+    let op = classes::ClassBuilder::default()
+        .name("ZCL_DEMO1")
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(class) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the class to be fetched without cache.");
+    };
+    let include = class.body().include(ClassInclude::Definitions).unwrap();
+
+    let op = classes::ClassSourceBuilder::default()
+        .name("ZCL_DEMO1")
+        .include(ClassInclude::Definitions)
+        .etag(include.etag().unwrap())
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(matches!(result, CacheControlled::NotModified(_)))
+}
+
+#[tokio::test]
+async fn class_versions_are_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = classes::ClassVersionsBuilder::default()
+        .name("ZCL_DEMO1")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().entries.is_empty());
+}
+
+#[tokio::test]
+async fn test_classes_are_updated() {
+    let client = common::setup_test_system_client();
+
+    let op = object::LockBuilder::default()
+        .object_uri("oo/classes/zcl_demo1")
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+
+    let ctx = client.create_user_session();
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    let handle = &result.body().lock_handle;
+
+    let op = object::UpdateSourceCodeBuilder::default()
+        .object(SourceCodeObject::ClassInclude(
+            "ZCL_DEMO1".into(),
+            ClassInclude::TestClasses,
+        ))
+        .content("*\"* use this source file for your ABAP unit test classes\n")
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    op.dispatch(&client, ctx).await.unwrap();
+
+    let op = object::UnlockBuilder::default()
+        .object_uri("oo/classes/zcl_demo1")
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    assert_eq!(result.status(), 200)
+}
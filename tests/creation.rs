@@ -0,0 +1,78 @@
+use adt_query::{
+    api::object,
+    dispatch::StatelessDispatch,
+    models::creation::{NewObjectBuilder, ObjectKind},
+};
+
+mod common;
+
+#[tokio::test]
+async fn program_is_validated_and_created() {
+    let client = common::setup_test_system_client();
+
+    let program = NewObjectBuilder::default()
+        .kind(ObjectKind::Program)
+        .name("Z_ADT_QUERY_CREATED")
+        .description("Created by adt_query")
+        .package("$TMP")
+        .master_language("EN")
+        .build()
+        .unwrap();
+
+    let op = object::ValidateObjectBuilder::default()
+        .object(&program)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().is_ok());
+
+    let op = object::CreateObjectBuilder::default()
+        .object(program)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.status(), 201);
+}
+
+#[tokio::test]
+async fn existing_class_fails_validation() {
+    let client = common::setup_test_system_client();
+
+    let class = NewObjectBuilder::default()
+        .kind(ObjectKind::Class { super_class: None })
+        .name("ZCL_DEMO1")
+        .description("Exists already")
+        .package("$TMP")
+        .build()
+        .unwrap();
+
+    let op = object::ValidateObjectBuilder::default()
+        .object(&class)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().is_ok());
+}
+
+#[tokio::test]
+async fn function_group_requires_transport_outside_local_package() {
+    let client = common::setup_test_system_client();
+
+    let group = NewObjectBuilder::default()
+        .kind(ObjectKind::FunctionGroup)
+        .name("ZADT_QUERY_FG")
+        .description("Created by adt_query")
+        .package("ZADT_QUERY")
+        .build()
+        .unwrap();
+
+    let op = object::CreateObjectBuilder::default()
+        .object(group)
+        .build()
+        .unwrap();
+
+    assert!(op.dispatch(&client).await.is_err());
+}
@@ -0,0 +1,135 @@
+use adt_query::{
+    api::{
+        cts,
+        object::{self, SourceCodeObject},
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::{
+        cts::{NewTransportRequestBuilder, ObjectEntryBuilder, TransportCheckRequestBuilder},
+        tpr::TransportStatus,
+    },
+};
+
+mod common;
+
+#[tokio::test]
+async fn modifiable_transports_of_user_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = cts::UserTransportsBuilder::default()
+        .user("DEVELOPER")
+        .status(TransportStatus::Modifiable)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(
+        result
+            .body()
+            .requests()
+            .all(|request| request.status.is_modifiable())
+    );
+}
+
+#[tokio::test]
+async fn transport_is_created_and_changed() {
+    let client = common::setup_test_system_client();
+
+    let request = NewTransportRequestBuilder::default()
+        .description("Created by adt_query")
+        .target("LOCAL")
+        .build()
+        .unwrap();
+    let op = cts::CreateTransportBuilder::default()
+        .request(&request)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    let number = result.body().request.as_ref().unwrap().number.clone();
+
+    let op = cts::ChangeDescriptionBuilder::default()
+        .number(number.as_str())
+        .description("Changed by adt_query")
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+
+    let op = cts::AddObjectsBuilder::default()
+        .number(number.as_str())
+        .object(
+            ObjectEntryBuilder::default()
+                .object_type("PROG")
+                .name("ZWEGWERF1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+
+    let op = cts::TransportRequestBuilder::default()
+        .number(number.as_str())
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    let transport = result.body().request.as_ref().unwrap();
+
+    assert_eq!(transport.description, "Changed by adt_query");
+    assert!(
+        transport
+            .all_objects()
+            .any(|object| object.name == "ZWEGWERF1")
+    );
+}
+
+#[tokio::test]
+async fn local_object_requires_no_transport() {
+    let client = common::setup_test_system_client();
+
+    let request = TransportCheckRequestBuilder::default()
+        .uri("/sap/bc/adt/programs/programs/zwegwerf1")
+        .package("$TMP")
+        .build()
+        .unwrap();
+    let op = cts::TransportChecksBuilder::default()
+        .request(&request)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(result.body().is_local());
+}
+
+#[tokio::test]
+async fn local_program_is_changed_without_transport() {
+    let client = common::setup_test_system_client();
+    let ctx = client.create_user_session();
+
+    let op = object::LockBuilder::default()
+        .object_uri("programs/programs/zwegwerf1")
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+    let lock = op.dispatch(&client, ctx).await.unwrap();
+
+    let transport = cts::transport_for(&client, "programs/programs/zwegwerf1", lock.body(), None)
+        .await
+        .unwrap();
+    assert_eq!(transport, None);
+
+    let mut op = object::UpdateSourceCodeBuilder::default();
+    op.object(SourceCodeObject::Program("ZWEGWERF1".into()))
+        .lock_handle(lock.body().lock_handle.as_str())
+        .content("REPORT zwegwerf1.\n");
+    if let Some(transport) = transport {
+        op.transport(transport);
+    }
+    op.build().unwrap().dispatch(&client, ctx).await.unwrap();
+
+    let op = object::UnlockBuilder::default()
+        .object_uri("programs/programs/zwegwerf1")
+        .lock_handle(lock.body().lock_handle.as_str())
+        .build()
+        .unwrap();
+    op.dispatch(&client, ctx).await.unwrap();
+}
@@ -0,0 +1,68 @@
+use adt_query::{api::datapreview, dispatch::StatelessDispatch};
+
+mod common;
+
+#[tokio::test]
+async fn freestyle_query_returns_typed_rows() {
+    let client = common::setup_test_system_client();
+
+    let op = datapreview::FreestyleQueryBuilder::default()
+        .query("SELECT devclass, as4user, created_on FROM tdevc WHERE devclass = '$TMP'")
+        .row_limit(5)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let table = result.body();
+    assert_eq!(table.columns.len(), 3);
+    assert_eq!(table.row_count(), 1);
+
+    let row = table.rows().next().unwrap();
+    assert_eq!(
+        row.get("DEVCLASS").as_ref().and_then(|v| v.as_str()),
+        Some("$TMP")
+    );
+    assert!(table.to_csv().starts_with("DEVCLASS,AS4USER,CREATED_ON\n"));
+}
+
+#[tokio::test]
+async fn ddic_table_is_previewed_with_filter() {
+    let client = common::setup_test_system_client();
+
+    let op = datapreview::EntityPreviewBuilder::default()
+        .entity("TDEVC")
+        .column("DEVCLASS")
+        .column("CREATED_BY")
+        .filter("devclass LIKE '$%'")
+        .order_by(datapreview::OrderBy::ascending("DEVCLASS"))
+        .row_limit(10)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let table = result.body();
+    assert_eq!(table.columns.len(), 2);
+    assert!(table.row_count() <= 10);
+    assert!(table.rows().all(|row| {
+        row.get("DEVCLASS")
+            .as_ref()
+            .and_then(|v| v.as_str())
+            .is_some_and(|name| name.starts_with('$'))
+    }));
+}
+
+#[tokio::test]
+async fn cds_view_is_previewed() {
+    let client = common::setup_test_system_client();
+
+    let op = datapreview::EntityPreviewBuilder::default()
+        .kind(datapreview::EntityKind::Cds)
+        .entity("I_LANGUAGE")
+        .row_limit(3)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(!result.body().columns.is_empty());
+    assert!(result.body().row_count() <= 3);
+}
@@ -0,0 +1,75 @@
+use adt_query::{
+    api::{deletion, object, repository},
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::{
+        creation::{NewObjectBuilder, ObjectKind},
+        deletion::{DeletionObject, DeletionObjectBuilder, DeletionRequestBuilder, DeletionStatus},
+    },
+};
+
+mod common;
+
+#[tokio::test]
+async fn local_program_is_deletable() {
+    let client = common::setup_test_system_client();
+
+    let op = repository::QuickSearchBuilder::default()
+        .query("zwegwerf1")
+        .package("$TMP")
+        .build()
+        .unwrap();
+    let references = op.dispatch(&client).await.unwrap();
+
+    let mut request = DeletionRequestBuilder::default();
+    for reference in references.body().references.iter() {
+        request.object(DeletionObject::from(reference));
+    }
+    let request = request.build().unwrap();
+
+    let op = deletion::CheckDeletionBuilder::default()
+        .request(&request)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.body().objects.len(), 1);
+    assert_eq!(result.body().objects[0].status(), DeletionStatus::Deletable);
+}
+
+#[tokio::test]
+async fn throwaway_program_is_deleted() {
+    let client = common::setup_test_system_client();
+
+    let program = NewObjectBuilder::default()
+        .kind(ObjectKind::Program)
+        .name("ZWEGWERF_DELETE")
+        .description("Deleted by adt_query")
+        .package("$TMP")
+        .build()
+        .unwrap();
+
+    let op = object::CreateObjectBuilder::default()
+        .object(program)
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+
+    let request = DeletionRequestBuilder::default()
+        .object(
+            DeletionObjectBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zwegwerf_delete")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let op = deletion::DeleteObjectsBuilder::default()
+        .request(&request)
+        .build()
+        .unwrap();
+
+    let ctx = client.create_user_session();
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    assert_eq!(result.body().failed().count(), 0);
+}
@@ -0,0 +1,97 @@
+use adt_query::{
+    api::{
+        functions,
+        object::{self, SourceCodeObject},
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::function::ParameterKind,
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn function_modules_of_group_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = functions::FunctionGroupContentsBuilder::default()
+        .name("ZDEMO")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(
+        result
+            .body()
+            .nodes_of_type(functions::FUNCTION_MODULE_TYPE)
+            .any(|node| node.object_name == "Z_DEMO_READ")
+    );
+}
+
+#[tokio::test]
+async fn function_module_parameters_are_read() {
+    let client = common::setup_test_system_client();
+
+    let op = functions::FunctionModuleParametersBuilder::default()
+        .group("ZDEMO")
+        .name("Z_DEMO_READ")
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(result) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the source to be fetched without cache.");
+    };
+    assert_eq!(result.body().name, "Z_DEMO_READ");
+    assert!(
+        result
+            .body()
+            .parameters_of(ParameterKind::Importing)
+            .next()
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn function_module_source_is_updated_with_group_lock() {
+    let client = common::setup_test_system_client();
+    let object = SourceCodeObject::FunctionModule("ZDEMO".into(), "Z_DEMO_READ".into());
+
+    let op = functions::FunctionModuleSourceBuilder::default()
+        .group("ZDEMO")
+        .name("Z_DEMO_READ")
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(source) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the source to be fetched without cache.");
+    };
+
+    let lock_uri = object.lock_uri();
+    let op = object::LockBuilder::default()
+        .object_uri(&lock_uri)
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+
+    let ctx = client.create_user_session();
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    let handle = &result.body().lock_handle;
+
+    let op = object::UpdateSourceCodeBuilder::default()
+        .object(object.clone())
+        .content(source.body().as_ref())
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    op.dispatch(&client, ctx).await.unwrap();
+
+    let op = object::UnlockBuilder::default()
+        .object_uri(&lock_uri)
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    assert_eq!(result.status(), 200)
+}
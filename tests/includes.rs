@@ -0,0 +1,40 @@
+use adt_query::{api::includes, dispatch::StatelessDispatch, response::CacheControlled};
+
+mod common;
+
+#[tokio::test]
+async fn include_source_is_read_in_main_program_context() {
+    let client = common::setup_test_system_client();
+
+    let op = includes::IncludeMainProgramsBuilder::default()
+        .name("ZBADICHECK_INC")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    let main_program = result.body().references.first().unwrap();
+
+    let op = includes::IncludeSourceBuilder::default()
+        .name("ZBADICHECK_INC")
+        .context(&main_program.uri)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(matches!(result, CacheControlled::Modified(_)))
+}
+
+#[tokio::test]
+async fn include_data_contains_context() {
+    let client = common::setup_test_system_client();
+
+    let op = includes::IncludeBuilder::default()
+        .name("ZBADICHECK_INC")
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(result) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the include to be fetched without cache.");
+    };
+    assert!(result.body().context.is_some());
+}
@@ -0,0 +1,92 @@
+use adt_query::{
+    api::{
+        interfaces,
+        object::{self, SourceCodeObject},
+    },
+    dispatch::{StatefulDispatch, StatelessDispatch},
+    models::{adtcore, interface::NewInterfaceBuilder},
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn interface_source_is_not_refetched_with_etag() {
+    let client = common::setup_test_system_client();
+
+    let op = interfaces::InterfaceBuilder::default()
+        .name("ZIF_DEMO1")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(interface) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the interface to be fetched without cache.");
+    };
+
+    let op = interfaces::InterfaceSourceBuilder::default()
+        .name("ZIF_DEMO1")
+        .etag(interface.body().source_etag().unwrap())
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(matches!(result, CacheControlled::NotModified(_)))
+}
+
+#[tokio::test]
+async fn interface_versions_are_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = interfaces::InterfaceVersionsBuilder::default()
+        .name("ZIF_DEMO1")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().entries.is_empty());
+}
+
+#[tokio::test]
+async fn interface_is_created_and_updated() {
+    let client = common::setup_test_system_client();
+
+    let interface = NewInterfaceBuilder::default()
+        .name("ZIF_ADT_QUERY_TEST")
+        .description("Created by adt_query")
+        .package("$TMP")
+        .build()
+        .unwrap();
+
+    object::create_object(&client, interface.into(), None)
+        .await
+        .unwrap();
+
+    let op = object::LockBuilder::default()
+        .object_uri("oo/interfaces/zif_adt_query_test")
+        .access_mode(object::AccessMode::Modify)
+        .build()
+        .unwrap();
+
+    let ctx = client.create_user_session();
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    let handle = &result.body().lock_handle;
+
+    let op = object::UpdateSourceCodeBuilder::default()
+        .object(SourceCodeObject::Interface("ZIF_ADT_QUERY_TEST".into()))
+        .content("INTERFACE zif_adt_query_test PUBLIC.\n  METHODS run.\nENDINTERFACE.\n")
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    op.dispatch(&client, ctx).await.unwrap();
+
+    let op = object::UnlockBuilder::default()
+        .object_uri("oo/interfaces/zif_adt_query_test")
+        .lock_handle(handle)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client, ctx).await.unwrap();
+    assert_eq!(result.status(), 200)
+}
@@ -0,0 +1,42 @@
+use adt_query::{
+    api::navigation::{self, NavigationFilter},
+    dispatch::StatelessDispatch,
+    models::abapsource::{Position, SourceLocation},
+};
+
+mod common;
+
+const SOURCE: &str =
+    "REPORT zwegwerf1.\nDATA lv_counter TYPE i.\nlv_counter = 1.\nIF lv_counter = 1.\nENDIF.\n";
+const SOURCE_URI: &str = "/sap/bc/adt/programs/programs/zwegwerf1/source/main";
+
+#[tokio::test]
+async fn definition_of_variable_is_found() {
+    let client = common::setup_test_system_client();
+
+    let op = navigation::NavigationTargetBuilder::default()
+        .location(SourceLocation::new(SOURCE_URI, Position::new(3, 2), None))
+        .source(SOURCE)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let location = result.body().location().unwrap();
+    assert_eq!(location.start.line, 2);
+}
+
+#[tokio::test]
+async fn matching_statement_is_found() {
+    let client = common::setup_test_system_client();
+
+    let op = navigation::NavigationTargetBuilder::default()
+        .location(SourceLocation::new(SOURCE_URI, Position::new(4, 0), None))
+        .filter(NavigationFilter::MatchingStatement)
+        .source(SOURCE)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let location = result.body().location().unwrap();
+    assert_eq!(location.start.line, 5);
+}
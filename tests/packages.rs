@@ -0,0 +1,71 @@
+use adt_query::{
+    api::{packages, programs},
+    dispatch::StatelessDispatch,
+    models::{
+        adtcore,
+        package::{NewPackageBuilder, PackageType},
+    },
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn package_of_program_is_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = programs::ProgramBuilder::default()
+        .name("ZDEMO1")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let CacheControlled::Modified(program) = op.dispatch(&client).await.unwrap() else {
+        panic!("Expected the program to be fetched without cache.");
+    };
+
+    let op = packages::PackageBuilder::default()
+        .name(&program.body().package.name)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.body().name, program.body().package.name);
+    assert_eq!(
+        result.body().attributes.package_type,
+        PackageType::Development
+    );
+}
+
+#[tokio::test]
+async fn package_contents_are_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = packages::PackageContentsBuilder::default()
+        .name("$TMP")
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().objects().count() > 0);
+}
+
+#[tokio::test]
+async fn existing_package_fails_validation() {
+    let client = common::setup_test_system_client();
+
+    let package = NewPackageBuilder::default()
+        .name("$TMP")
+        .description("Local Objects")
+        .software_component("LOCAL")
+        .build()
+        .unwrap();
+
+    let op = packages::ValidatePackageBuilder::default()
+        .package(&package)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().is_ok());
+}
@@ -0,0 +1,46 @@
+use adt_query::{
+    api::quickfixes,
+    dispatch::StatelessDispatch,
+    models::abapsource::{Position, SourceLocation},
+};
+
+mod common;
+
+const SOURCE: &str = "REPORT zwegwerf1.\nlv_total = 1.\n";
+const SOURCE_URI: &str = "/sap/bc/adt/programs/programs/zwegwerf1/source/main";
+
+#[tokio::test]
+async fn undeclared_variable_can_be_declared() {
+    let client = common::setup_test_system_client();
+    let location = SourceLocation::new(SOURCE_URI, Position::new(2, 2), None);
+
+    let op = quickfixes::QuickFixProposalsBuilder::default()
+        .location(location.clone())
+        .source(SOURCE)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let proposal = result
+        .body()
+        .proposals
+        .iter()
+        .find(|proposal| proposal.title().to_lowercase().contains("declare"))
+        .expect("declaring the variable is proposed");
+
+    let op = quickfixes::ApplyQuickFixBuilder::default()
+        .proposal(proposal)
+        .location(location)
+        .source(SOURCE)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let deltas = result.body().deltas();
+    assert!(!deltas.is_empty());
+    assert!(
+        deltas
+            .iter()
+            .any(|delta| delta.content.to_uppercase().contains("LV_TOTAL"))
+    );
+}
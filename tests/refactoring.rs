@@ -0,0 +1,55 @@
+use adt_query::{
+    api::refactoring,
+    dispatch::StatelessDispatch,
+    models::{
+        abapsource::{Position, SourceLocation},
+        refactoring::{Refactoring, Rename},
+    },
+};
+
+mod common;
+
+#[tokio::test]
+async fn rename_of_variable_is_previewed() {
+    let client = common::setup_test_system_client();
+
+    let op = refactoring::EvaluateBuilder::<Rename>::default()
+        .location(SourceLocation::new(
+            "/sap/bc/adt/programs/programs/zwegwerf1/source/main",
+            Position::new(7, 6),
+            None,
+        ))
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let mut rename = result.body().clone();
+    rename.set_new_name("gtyt_renamed");
+
+    let op = refactoring::PreviewBuilder::default()
+        .refactoring(&rename)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert!(
+        result
+            .body()
+            .change_set()
+            .edits()
+            .all(|(_, delta)| delta.new_content.eq_ignore_ascii_case("gtyt_renamed"))
+    );
+}
+
+#[tokio::test]
+async fn change_package_is_evaluated() {
+    let client = common::setup_test_system_client();
+
+    let op = refactoring::EvaluateChangePackageBuilder::default()
+        .object_uri("/sap/bc/adt/programs/programs/zwegwerf1")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    assert_eq!(result.body().old_package, "$TMP");
+}
@@ -2,7 +2,11 @@ use adt_query::{
     dispatch::StatelessDispatch,
     {
         api,
-        models::vfs::{Facet, FacetOrderBuilder, PreselectionBuilder},
+        models::{
+            nameditem::NamedItemList,
+            usagereferences::SnippetObject,
+            vfs::{Facet, FacetOrderBuilder, PreselectionBuilder},
+        },
     },
 };
 
@@ -96,3 +100,85 @@ async fn no_transports_are_retrieved() {
     let result = op.dispatch(&client).await.unwrap();
     assert!(result.body().transports.is_empty())
 }
+
+#[tokio::test]
+async fn owner_property_values_are_retrieved() {
+    let client = common::setup_test_system_client();
+
+    let op = api::repository::PropertyValuesBuilder::default()
+        .facet(Facet::Owner)
+        .name("DEV*")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().items.iter().any(|v| v.name == "DEVELOPER"));
+}
+
+#[tokio::test]
+async fn facet_template_link_is_followed() {
+    let client = common::setup_test_system_client();
+
+    let facets = api::repository::AvailableFacets::default()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    let link = facets
+        .body()
+        .facets
+        .iter()
+        .find(|f| f.key == "owner")
+        .and_then(|f| f.values_uri.as_ref())
+        .unwrap();
+
+    let op = api::core::FollowTemplateLinkBuilder::<NamedItemList>::default()
+        .link(link)
+        .variable("name", "DEVELOPER")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert_eq!(result.body().items[0].name, "DEVELOPER");
+}
+
+#[tokio::test]
+async fn objects_are_found_by_quick_search() {
+    let client = common::setup_test_system_client();
+
+    let op = api::repository::QuickSearchBuilder::default()
+        .query("ZWEGWERF*")
+        .object_type("PROG/P")
+        .package("$TMP")
+        .max_results(10)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(
+        result
+            .body()
+            .references
+            .iter()
+            .any(|r| r.name == "ZWEGWERF1" && r.object_type == "PROG/P")
+    );
+}
+
+#[tokio::test]
+async fn usage_references_and_snippets_are_retrieved() {
+    let client = common::setup_test_system_client();
+
+    let op = api::repository::UsageReferencesBuilder::default()
+        .object_uri("/sap/bc/adt/oo/classes/cl_ris_adt_res_app")
+        .object_type("CLAS/OC")
+        .build()
+        .unwrap();
+    let references = op.dispatch(&client).await.unwrap();
+    let result = references.body().results().next().unwrap();
+
+    let op = api::repository::UsageSnippetsBuilder::default()
+        .object(SnippetObject::new(
+            result.object_identifier.as_deref().unwrap(),
+            result.uri.as_str(),
+        ))
+        .build()
+        .unwrap();
+    let snippets = op.dispatch(&client).await.unwrap();
+    assert!(!snippets.body().snippet_objects.objects.is_empty());
+}